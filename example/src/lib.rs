@@ -4,13 +4,19 @@ use std::sync::atomic::AtomicU8;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum_autoroute::AutorouteApiRouter;
+use axum_extra::extract::cookie::Key;
 use routes::{
-    body_json, body_multipart, body_raw, hello, params_path, params_query, response_cookie, response_json, state,
+    body_form, body_json, body_json_lines, body_multipart, body_raw, hello, params_path, params_query, response_cookie,
+    response_json, state,
 };
 use utoipa::OpenApi;
 
 pub use self::doc::OpenApiDoc;
-use crate::routes::{main_example, response_file, route_info};
+use crate::routes::{
+    callbacks, common_schema, cors, doc_markdown, impl_openapi, main_example, metrics, module_openapi,
+    multiple_methods, parts_extractors, request_timeout, response_custom_status, response_default, response_file,
+    response_paginated, response_problem, response_range, response_result, route_info, security,
+};
 
 pub mod routes;
 #[cfg(test)]
@@ -42,7 +48,7 @@ mod doc {
 
 pub fn app() -> AutorouteApiRouter {
     let state = ApiState::new();
-    AutorouteApiRouter::new_with_openapi(OpenApiDoc::openapi())
+    let router = AutorouteApiRouter::new_with_openapi(OpenApiDoc::openapi())
         .fallback(fallback_handler)
         .merge(hello::router())
         .merge(main_example::router())
@@ -51,11 +57,34 @@ pub fn app() -> AutorouteApiRouter {
         .merge(params_query::router())
         .merge(state::router().with_state(state))
         .merge(body_json::router())
+        .merge(body_json_lines::router())
+        .merge(body_form::router())
         .merge(body_raw::router())
         .merge(body_multipart::router())
         .merge(response_cookie::router())
         .merge(response_file::router())
+        .merge(response_default::router())
+        .merge(response_problem::router())
+        .merge(response_paginated::router())
+        .merge(response_range::router())
+        .merge(response_custom_status::router())
+        .merge(response_result::router())
         .merge(route_info::router())
+        .merge(impl_openapi::router())
+        .merge(common_schema::router())
+        .merge(security::router())
+        .merge(parts_extractors::router())
+        .merge(parts_extractors::router_cookie_key(Key::generate()))
+        .merge(multiple_methods::router())
+        .merge(request_timeout::router())
+        .merge(cors::router())
+        .merge(doc_markdown::router())
+        .merge(callbacks::router())
+        .merge(module_openapi::router())
+        .merge(metrics::router());
+    #[cfg(feature = "yaml")]
+    let router = router.merge(routes::response_yaml::router());
+    router
 }
 
 async fn fallback_handler() -> Response {
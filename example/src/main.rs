@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use std::{env, fs};
 
 use axum::Router;
+use axum_autoroute::AutorouteApiRouter;
 use axum_autoroute_example::app;
 use tokio::signal;
 use utoipa::openapi::OpenApi;
@@ -21,14 +22,16 @@ async fn main() {
     println!("server listening at {listener_addr}");
 
     // init router
-    let (router, doc) = app().split_for_parts();
+    let app = app();
+    let openapi_uis = serve_openapi_uis(&app, &listener_addr);
+    let (router, doc) = app.split_for_parts();
 
     // save doc
     save_openapi_doc(&doc);
 
     // add swagger ui
     // done after the middlewares so that they are not applied here
-    let router = router.merge(serve_swagger_ui(doc, &listener_addr));
+    let router = router.merge(serve_swagger_ui(doc, &listener_addr)).merge(openapi_uis);
 
     // start server
     axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
@@ -81,6 +84,15 @@ fn serve_swagger_ui(doc: OpenApi, addr: &SocketAddr) -> Router {
     Router::new().merge(SwaggerUi::new(swagger_route).url(openapi_route, doc))
 }
 
+fn serve_openapi_uis(app: &AutorouteApiRouter, addr: &SocketAddr) -> Router {
+    println!("serve redoc at route 'http://{addr}/redoc'");
+    println!("serve rapidoc at route 'http://{addr}/rapidoc'");
+    println!("serve scalar at route 'http://{addr}/scalar'");
+    app.serve_redoc("/redoc", "/redoc/openapi.json")
+        .merge(app.serve_rapidoc("/rapidoc", "/rapidoc/openapi.json"))
+        .merge(app.serve_scalar("/scalar", "/scalar/openapi.json"))
+}
+
 #[cfg(feature = "tracing")]
 fn init_tracing() {
     use tracing_subscriber::layer::SubscriberExt;
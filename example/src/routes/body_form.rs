@@ -0,0 +1,112 @@
+use axum::extract::{Form, RawForm};
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(body_form, body_raw_form))
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// The url encoded form body type
+struct MyBodyForm {
+    /// A name
+    name: String,
+    /// A number
+    num: u32,
+    /// An optional flag
+    flag: Option<bool>,
+}
+
+/// Parse an url encoded form and return it as json
+#[autoroute(POST, path="/body/form", tags=["body"],
+    external_docs=("https://docs.rs/axum/latest/axum/struct.Form.html", "Documentation of the axum Form extractor"),
+    responses=[
+        (200, body=MyBodyForm, description="Returns the received form"),
+    ]
+)]
+async fn body_form(Form(form): Form<MyBodyForm>) -> BodyFormResponses {
+    form.into_ok()
+}
+
+/// Return the raw bytes of an url encoded form
+#[autoroute(POST, path="/body/raw_form", tags=["body"],
+    responses=[
+        (200, body=String, serializer=NONE, description="Returns the received form, without parsing it"),
+    ]
+)]
+async fn body_raw_form(RawForm(form): RawForm) -> BodyRawFormResponses {
+    String::from_utf8_lossy(&form).into_owned().into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::{Method, Request, StatusCode};
+    use serde_json::json;
+    use tower::Service;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    fn request_form(form: &'static str) -> Request<Body> {
+        request_form_to("/body/form", form)
+    }
+
+    fn request_form_to(uri: &'static str, form: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(form))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn body_form() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service.call(request_form("name=my+name&num=32")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"name": "my name", "num": 32, "flag": null})
+        );
+
+        let response = service.call(request_form("name=test&num=NaN")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert_traces!("body_form.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn body_raw_form() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_form_to("/body/raw_form", "name=my+name&num=NaN"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "name=my+name&num=NaN");
+
+        let response = service
+            .call(request_json(Method::POST, "/body/raw_form", &json!({"name": "test"})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        assert_traces!("body_raw_form.traces");
+    }
+
+    #[test]
+    fn body_form_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("body_form.openapi.json", &doc);
+    }
+}
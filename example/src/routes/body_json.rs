@@ -41,17 +41,23 @@ struct MyStruct<T> {
 }
 
 /// Parse and return the provided body
-#[autoroute(POST, path="/body/json/1", tags=["body"],
+#[autoroute(POST, path="/body/json/1", tags=["body"], auto_document_rejections=true,
     responses=[
         (200, body=MyBodyJson, description="Returns the received body"),
     ]
 )]
-async fn body_json1(Json(json): Json<MyBodyJson>) -> BodyJson1Responses {
+async fn body_json1(
+    #[extractor(examples=[
+        ("empty", serde_json::json!({"collection": []})),
+        ("variants", serde_json::json!({"collection": ["Variant1", {"Variant3": {"value": 32}}]})),
+    ])]
+    Json(json): Json<MyBodyJson>,
+) -> BodyJson1Responses {
     json.into_ok()
 }
 
 /// Same as above, but the tracing will be different if the feature is activated because the json extractor is not destructured
-#[autoroute(POST, path="/body/json/2", tags=["body"],
+#[autoroute(POST, path="/body/json/2", tags=["body"], deprecated=true,
     responses=[
         (200, body=MyBodyJson, description="Returns the received body"),
     ]
@@ -63,6 +69,8 @@ async fn body_json2(json: Json<MyBodyJson>) -> BodyJson2Responses {
 #[cfg(test)]
 mod test {
     use axum::http::{Method, StatusCode};
+    use axum::response::Response;
+    use axum_autoroute::autoroute_test;
     use serde_json::{Value, json};
     use tower::Service;
 
@@ -96,9 +104,94 @@ mod test {
         assert_traces!("body_json.traces");
     }
 
+    #[tokio::test]
+    async fn body_json_rejection() {
+        let (mut router, doc) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        // the json data does not match the expected body type
+        let response = service
+            .call(request_json(Method::POST, "/body/json/1", &json!({"collection": 1})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // the rejection is only documented when `auto_document_rejections` is enabled
+        let responses = &doc.paths.paths["/body/json/1"]
+            .post
+            .as_ref()
+            .unwrap()
+            .responses
+            .responses;
+        assert!(responses.contains_key("422"));
+        let responses = &doc.paths.paths["/body/json/2"]
+            .post
+            .as_ref()
+            .unwrap()
+            .responses
+            .responses;
+        assert!(!responses.contains_key("422"));
+    }
+
+    #[autoroute_test(handler=super::body_json1, body=json!({"collection": ["Variant1", {"Variant3": {"value": 32}}]}))]
+    #[tokio::test]
+    async fn body_json_autoroute_test(response: Response) {
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"collection": ["Variant1", {"Variant3": {"value": 32}}]})
+        );
+    }
+
+    #[autoroute_test(handler=super::body_json1, body=json!({"collection": 1}), expected_status=UNPROCESSABLE_ENTITY)]
+    #[tokio::test]
+    async fn body_json_autoroute_test_rejection() {}
+
     #[test]
     fn body_json_openapi() {
         let (_, doc) = router().split_for_parts();
         check_openapi("body_json.openapi.json", &doc);
     }
+
+    #[test]
+    fn body_json_examples() {
+        let (_, doc) = router().split_for_parts();
+        let request_body = doc.paths.paths["/body/json/1"]
+            .post
+            .as_ref()
+            .unwrap()
+            .request_body
+            .as_ref()
+            .unwrap();
+        let examples = &request_body.content["application/json"].examples;
+        assert_eq!(examples.keys().collect::<Vec<_>>(), ["empty", "variants"]);
+
+        // the examples are only set on the annotated extractor
+        let request_body = doc.paths.paths["/body/json/2"]
+            .post
+            .as_ref()
+            .unwrap()
+            .request_body
+            .as_ref()
+            .unwrap();
+        assert!(request_body.content["application/json"].examples.is_empty());
+    }
+
+    #[test]
+    fn body_json_validate_openapi() {
+        assert_eq!(router().validate_openapi(), Ok(()));
+
+        // remove a schema referenced by the routes
+        let router = router().map_openapi(|doc| {
+            if let Some(components) = doc.components.as_mut() {
+                components.schemas.remove("MyBodyJson");
+            }
+        });
+        let errors = router.validate_openapi().unwrap_err();
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(
+            errors
+                .iter()
+                .all(|error| error.starts_with("dangling reference '#/components/schemas/MyBodyJson' at '/paths/"))
+        );
+    }
 }
@@ -0,0 +1,122 @@
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use axum_extra::extract::JsonLines;
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(body_json_lines))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+/// A measure sent on each line of the body
+struct Measure {
+    /// name of the sensor
+    sensor: String,
+    /// the measured value
+    value: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// Summary of the uploaded measures
+struct MeasuresSummary {
+    /// number of received measures
+    count: usize,
+    /// sum of the received values
+    total: u32,
+    /// the sensors that sent the measures, in order of first appearance
+    sensors: Vec<String>,
+}
+
+/// Upload measures as newline delimited json
+#[autoroute(POST, path="/body/json_lines", tags=["body"],
+    responses=[
+        (200, body=MeasuresSummary, description="Summary of the uploaded measures"),
+        (400, body=String, serializer=NONE, description="A line is not a valid measure"),
+    ]
+)]
+async fn body_json_lines(mut measures: JsonLines<Measure>) -> BodyJsonLinesResponses {
+    let mut summary = MeasuresSummary {
+        count: 0,
+        total: 0,
+        sensors: Vec::new(),
+    };
+    loop {
+        match measures.try_next().await {
+            Ok(Some(measure)) => {
+                summary.count += 1;
+                summary.total += measure.value;
+                if !summary.sensors.contains(&measure.sensor) {
+                    summary.sensors.push(measure.sensor);
+                }
+            }
+            Ok(None) => return summary.into_ok(),
+            Err(err) => return err.to_string().into_bad_request(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode, header};
+    use serde_json::json;
+    use tower::Service;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    fn request_json_lines(body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .uri("/body/json_lines")
+            .header(header::CONTENT_TYPE, "application/x-ndjson")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn body_json_lines() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_json_lines(
+                "{\"sensor\": \"a\", \"value\": 3}\n{\"sensor\": \"b\", \"value\": 4}\n{\"sensor\": \"a\", \"value\": 5}\n",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"count": 3, "total": 12, "sensors": ["a", "b"]})
+        );
+
+        // the second line is not a valid measure
+        let response = service
+            .call(request_json_lines(
+                "{\"sensor\": \"a\", \"value\": 3}\n{\"sensor\": \"b\"}\n",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn body_json_lines_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("body_json_lines.openapi.json", &doc);
+
+        let request_body = doc.paths.paths["/body/json_lines"]
+            .post
+            .as_ref()
+            .unwrap()
+            .request_body
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            request_body.content.keys().collect::<Vec<_>>(),
+            ["application/x-ndjson"]
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use axum::body::Bytes;
-use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use axum::extract::Multipart;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
 use axum_typed_multipart::{FieldData, TryFromField, TryFromMultipart, TypedMultipart};
 use utoipa::ToSchema;
 
 pub fn router() -> AutorouteApiRouter {
-    AutorouteApiRouter::new().with_pub_route(method_router!(body_multipart))
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(body_multipart, body_multipart_raw))
 }
 
 #[derive(Debug, TryFromMultipart, ToSchema)]
@@ -52,6 +53,31 @@ async fn body_multipart(TypedMultipart(mpart): TypedMultipart<MultipartStructure
     .into_ok()
 }
 
+/// Parse a multipart body whose fields are not known in advance
+#[autoroute(POST, path="/body/multipart/raw", tags=["body"],
+    responses=[
+        (200, body=String, serializer=NONE, description="Returns the name and size of each received field"),
+        (400, body=String, serializer=NONE, description="The multipart body is malformed"),
+    ]
+)]
+async fn body_multipart_raw(mut mpart: Multipart) -> BodyMultipartRawResponses {
+    let mut fields = Vec::new();
+    loop {
+        match mpart.next_field().await {
+            Ok(Some(field)) => {
+                let name = field.name().unwrap_or_default().to_string();
+                match field.bytes().await {
+                    Ok(bytes) => fields.push(format!("{name}={}", bytes.len())),
+                    Err(err) => return err.body_text().into_bad_request(),
+                }
+            }
+            Ok(None) => break,
+            Err(err) => return err.body_text().into_bad_request(),
+        }
+    }
+    fields.join(", ").into_ok()
+}
+
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
@@ -113,6 +139,53 @@ mod test {
         assert_traces!("body_multipart.traces");
     }
 
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn body_multipart_raw() {
+        let (router, _) = router().split_for_parts();
+
+        let mut form = Form::default();
+        form.add_text("num", "32");
+        form.add_text("name", "name a");
+        form.add_reader_file_with_mime("file", Cursor::new("file content"), "text_file.txt", mime::TEXT_PLAIN);
+
+        let content_type = form.content_type();
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/body/multipart/raw")
+                    .header(CONTENT_TYPE, content_type)
+                    .body(Body::from_stream(MPartBody::from(form)))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "num=2, name=6, file=12");
+
+        assert_traces!("body_multipart_raw.traces");
+    }
+
+    #[tokio::test]
+    async fn body_multipart_raw_bad_request() {
+        let (router, _) = router().split_for_parts();
+
+        // missing boundary
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/body/multipart/raw")
+                    .header(CONTENT_TYPE, "multipart/form-data")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[test]
     fn body_multipart_openapi() {
         let (_, doc) = router().split_for_parts();
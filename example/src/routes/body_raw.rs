@@ -1,8 +1,8 @@
-use axum::body::{Body, to_bytes};
+use axum::body::{Body, Bytes, to_bytes};
 use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
 
 pub fn router() -> AutorouteApiRouter {
-    AutorouteApiRouter::new().with_pub_routes(method_routers!(body_raw))
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(body_raw, body_bytes))
 }
 
 /// Receives a raw body and return its bytes size or fail if it is greater than 100 bytes
@@ -22,6 +22,16 @@ async fn body_raw(body: Body) -> BodyRawResponses {
     }
 }
 
+/// Receives the whole raw body and return its bytes size
+#[autoroute(POST, path="/body/bytes", tags=["body"],
+    responses=[
+        (200, body=usize, description="Returns the size of the received body"),
+    ]
+)]
+async fn body_bytes(bytes: Bytes) -> BodyBytesResponses {
+    bytes.len().into_ok()
+}
+
 #[cfg(test)]
 mod test {
     use axum::body::Body;
@@ -70,6 +80,28 @@ mod test {
         assert_traces!("body_raw.traces");
     }
 
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn body_bytes() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/body/bytes")
+                    .body(Body::from(vec![1u8, 2, 3]))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, 3);
+
+        assert_traces!("body_bytes.traces");
+    }
+
     #[test]
     fn body_json_openapi() {
         let (_, doc) = router().split_for_parts();
@@ -0,0 +1,75 @@
+use axum::Json;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(subscribe))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+/// A subscription to the events of the api
+struct Subscription {
+    /// The url notified of the events
+    callback_url: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// An event notified to the subscribers
+struct EventPayload {
+    /// The name of the event
+    event: String,
+}
+
+/// Subscribe to the events of the api, which will be sent to the given url
+#[autoroute(POST, path="/callbacks/subscribe", tags=["callbacks"],
+    responses=[
+        (CREATED, body=String, serializer=NONE, description="The subscription was registered"),
+    ],
+    callbacks=[
+        ("onEvent", POST, "{$request.body#/callback_url}", body=EventPayload, responses=[
+            (OK, body=String, serializer=NONE, description="The event was received"),
+            (DEFAULT, body=String, serializer=NONE, description="The event was rejected"),
+        ]),
+        ("onUnsubscribe", DELETE, "{$request.body#/callback_url}", responses=[
+            (NO_CONTENT, body=String, serializer=NONE, description="The subscription was removed"),
+        ]),
+    ]
+)]
+async fn subscribe(Json(subscription): Json<Subscription>) -> SubscribeResponses {
+    format!("subscribed {}", subscription.callback_url).into_created()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn callbacks() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_json(
+                Method::POST,
+                "/callbacks/subscribe",
+                &json!({"callback_url": "https://example.com/webhook"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            response_to_str(response).await,
+            "subscribed https://example.com/webhook"
+        );
+    }
+
+    #[test]
+    fn callbacks_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("callbacks.openapi.json", &doc);
+    }
+}
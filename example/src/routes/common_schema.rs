@@ -0,0 +1,98 @@
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_routes(method_routers!(always_fail))
+        .with_common_response_schema::<ProblemDetail>()
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// A generic error structure shared by several routes
+struct ProblemDetail {
+    /// short description of the problem
+    title: String,
+    /// list of the problems detected
+    violations: Vec<Violation>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// A single problem detected
+struct Violation {
+    /// name of the faulty field
+    field: String,
+    /// reason of the failure
+    reason: String,
+}
+
+/// This route always fails.
+#[autoroute(GET, path="/common/fail", tags=["common_schema"],
+    responses=[
+        (BAD_REQUEST, body=String, serializer=NONE, description="Always fails, see the `ProblemDetail` schema"),
+    ]
+)]
+async fn always_fail() -> AlwaysFailResponses {
+    "failure".to_string().into_bad_request()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::StatusCode;
+    use utoipa::openapi::schema::{ObjectBuilder, Type};
+    use utoipa::openapi::{Ref, RefOr};
+
+    use super::router;
+    use crate::routes::response_json;
+    use crate::test_utils::*;
+
+    #[test]
+    fn common_schema_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("common_schema.openapi.json", &doc);
+    }
+
+    #[test]
+    fn common_schema_global_response() {
+        let (_, doc) = router()
+            .add_global_response(
+                StatusCode::UNAUTHORIZED,
+                "Missing or invalid credentials",
+                Ref::from_schema_name("ProblemDetail"),
+            )
+            .add_global_response(
+                StatusCode::BAD_REQUEST,
+                "Already documented by the route",
+                ObjectBuilder::new().schema_type(Type::String),
+            )
+            .add_global_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                "Rate limit exceeded",
+                ObjectBuilder::new().schema_type(Type::String),
+            )
+            // added after the global responses, still documented with them
+            .merge(response_json::router())
+            .split_for_parts();
+
+        assert_eq!(doc.paths.paths.len(), 2);
+        for item in doc.paths.paths.values() {
+            let responses = &item.get.as_ref().unwrap().responses.responses;
+            assert!(responses.contains_key("401"));
+            assert!(responses.contains_key("429"));
+        }
+
+        // the response documented by the route is not overridden
+        let RefOr::T(bad_request) = &doc.paths.paths["/common/fail"]
+            .get
+            .as_ref()
+            .unwrap()
+            .responses
+            .responses["400"]
+        else {
+            panic!("unexpected reference");
+        };
+        assert_eq!(bad_request.description, "Always fails, see the `ProblemDetail` schema");
+
+        check_openapi("common_schema_global_response.openapi.json", &doc);
+    }
+}
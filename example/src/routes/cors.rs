@@ -0,0 +1,96 @@
+use axum::http::{HeaderValue, Method};
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use tower_http::cors::CorsLayer;
+
+pub fn router() -> AutorouteApiRouter {
+    let cors_layer = CorsLayer::new()
+        .allow_origin(HeaderValue::from_static("https://example.com"))
+        .allow_methods([Method::GET, Method::POST]);
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(cors))
+        .with_cors(cors_layer, &["https://example.com"], &[Method::GET, Method::POST])
+}
+
+/// This route can be called from the `https://example.com` origin
+#[autoroute(GET, path="/cors", tags=["cors"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Always return OK"),
+    ]
+)]
+async fn cors() -> CorsResponses {
+    "OK".to_string().into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::{Method, StatusCode, header};
+    use tower::Service;
+    use utoipa::openapi::RefOr;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn cors() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/cors")
+            .header(header::ORIGIN, "https://example.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = service.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://example.com"
+        );
+        assert_eq!(response_to_str(response).await, "OK");
+
+        // preflight request
+        let request = Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/cors")
+            .header(header::ORIGIN, "https://example.com")
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+            .body(Body::empty())
+            .unwrap();
+        let response = service.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_METHODS], "GET,POST");
+
+        // the origin is not allowed, the browser will reject the response
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/cors")
+            .header(header::ORIGIN, "https://other.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = service.call(request).await.unwrap();
+        assert_eq!(
+            response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn cors_openapi() {
+        let (_, doc) = router().split_for_parts();
+        let responses = &doc.paths.paths["/cors"].get.as_ref().unwrap().responses.responses;
+        let RefOr::T(response) = &responses["200"] else {
+            panic!("unexpected response reference");
+        };
+        assert_eq!(
+            response.headers["access-control-allow-origin"].description.as_deref(),
+            Some("The origins allowed to access the resource: https://example.com")
+        );
+        assert!(response.headers.contains_key("access-control-allow-methods"));
+        assert!(response.headers.contains_key("access-control-allow-headers"));
+
+        check_openapi("cors.openapi.json", &doc);
+    }
+}
@@ -1,5 +1,5 @@
 use axum::Json;
-use axum::extract::{FromRequest, FromRequestParts, Query};
+use axum::extract::{FromRequest, FromRequestParts, Query, Request};
 use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
 use serde::Deserialize;
 use utoipa::{IntoParams, OpenApi, ToSchema};
@@ -12,11 +12,14 @@ pub fn router() -> AutorouteApiRouter {
         custom_body_extractor_2,
         custom_body_extractor_3,
         custom_body_extractor_4,
+        custom_body_extractor_5,
+        custom_body_extractor_6,
         custom_query_extractor_1,
         custom_query_extractor_2,
         custom_query_extractor_3,
         custom_query_extractor_4,
         custom_query_extractor_5,
+        custom_query_extractor_6,
     ))
 }
 
@@ -70,13 +73,51 @@ async fn custom_body_extractor_3(
     ]
 )]
 async fn custom_body_extractor_4(
-    #[extractor(content_type=APPLICATION_JSON, content_type="application/yaml", trace = false)] j: CustomJsonExtractor<
+    #[extractor(content_type=APPLICATION_JSON, content_type=APPLICATION_YAML, trace = false)] j: CustomJsonExtractor<
         MyJsonStruct,
     >,
 ) -> CustomBodyExtractor4Responses {
     j.0.txt.into_ok()
 }
 
+/// `rejection_status` defined, the rejection of the extractor is documented in openapi
+#[autoroute(POST, path="/extractor/custom_body5", tags=["custom extractor"],
+    responses=[
+        (OK, body=String, serializer=NONE),
+    ]
+)]
+async fn custom_body_extractor_5(
+    #[extractor(content_type=APPLICATION_JSON, rejection_status=UNPROCESSABLE_ENTITY)] j: CustomJsonExtractor<
+        MyJsonStruct,
+    >,
+) -> CustomBodyExtractor5Responses {
+    j.0.txt.into_ok()
+}
+
+/// An identifier read from a text body, which does not implement `utoipa::ToSchema`
+#[derive(Debug)]
+struct CustomIdExtractor(String);
+
+impl<S: Send + Sync> FromRequest<S> for CustomIdExtractor {
+    type Rejection = <String as FromRequest<S>>::Rejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        String::from_request(req, state).await.map(Self)
+    }
+}
+
+/// `schema_type` and `schema_format` defined, the schema of the body is overridden in openapi
+#[autoroute(POST, path="/extractor/custom_body6", tags=["custom extractor"],
+    responses=[
+        (OK, body=String, serializer=NONE),
+    ]
+)]
+async fn custom_body_extractor_6(
+    #[extractor(content_type=TEXT_PLAIN, schema_type="string", schema_format="uuid")] id: CustomIdExtractor,
+) -> CustomBodyExtractor6Responses {
+    id.0.into_ok()
+}
+
 #[derive(Debug, FromRequestParts)]
 #[from_request(via(Query))]
 struct CustomQueryExtractor<T>(T);
@@ -145,8 +186,28 @@ async fn custom_query_extractor_5(
     q.0.num.to_string().into_ok()
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in=Query)]
+struct MyIdQuery {
+    id: String,
+}
+
+/// `schema_type` and `schema_format` defined, the schema of the parameters is overridden in openapi
+#[autoroute(POST, path="/extractor/custom_parts6", tags=["custom extractor"],
+    responses=[
+        (OK, body=String, serializer=NONE),
+    ]
+)]
+async fn custom_query_extractor_6(
+    #[extractor(into_params = true, schema_type = "string", schema_format = "uuid")] q: CustomQueryExtractor<MyIdQuery>,
+) -> CustomQueryExtractor6Responses {
+    q.0.id.into_ok()
+}
+
 #[cfg(test)]
 mod test {
+    use axum::body::Body;
+    use axum::extract::Request;
     use axum::http::{Method, StatusCode};
     use serde_json::json;
     use tower::Service;
@@ -176,6 +237,22 @@ mod test {
         assert_traces!("custom_body_extractor.traces");
     }
 
+    #[tokio::test]
+    async fn custom_body_extractor_rejection() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_json(
+                Method::POST,
+                "/extractor/custom_body5",
+                &json!({"unknown": "missing txt field"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[tokio::test]
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     async fn custom_query_extractor() {
@@ -197,6 +274,44 @@ mod test {
         assert_traces!("custom_query_extractor.traces");
     }
 
+    #[tokio::test]
+    async fn custom_extractor_schema_override() {
+        let (mut router, doc) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let response = service
+            .call(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/extractor/custom_body6")
+                    .body(Body::from(id))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, id);
+        let response = service
+            .call(request_empty(
+                Method::POST,
+                &format!("/extractor/custom_parts6?id={id}"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, id);
+
+        let uuid_schema = json!({"type": "string", "format": "uuid"});
+        let operation = doc.paths.paths["/extractor/custom_body6"].post.as_ref().unwrap();
+        let content = &operation.request_body.as_ref().unwrap().content["text/plain"];
+        assert_eq!(json!(content.schema), uuid_schema);
+        let operation = doc.paths.paths["/extractor/custom_parts6"].post.as_ref().unwrap();
+        let parameter = &operation.parameters.as_ref().unwrap()[0];
+        assert_eq!(parameter.name, "id");
+        assert_eq!(json!(parameter.schema), uuid_schema);
+    }
+
     #[test]
     fn custom_extractor_openapi() {
         let (_, doc) = router().split_for_parts();
@@ -0,0 +1,53 @@
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(doc_markdown))
+}
+
+/// This route is documented in a markdown file.
+///
+#[doc = include_str!("doc_markdown.md")]
+#[autoroute(GET, path="/doc/markdown", tags=["doc"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Always says hello"),
+    ]
+)]
+async fn doc_markdown() -> DocMarkdownResponses {
+    "Hello World!".to_string().into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn doc_markdown() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/doc/markdown"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "Hello World!");
+    }
+
+    #[test]
+    fn doc_markdown_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("doc_markdown.openapi.json", &doc);
+
+        let operation = doc.paths.paths["/doc/markdown"].get.as_ref().unwrap();
+        assert_eq!(
+            operation.summary.as_deref(),
+            Some("This route is documented in a markdown file.")
+        );
+        assert_eq!(
+            operation.description.as_deref(),
+            Some(include_str!("doc_markdown.md").trim())
+        );
+    }
+}
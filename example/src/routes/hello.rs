@@ -10,6 +10,9 @@ pub fn router() -> AutorouteApiRouter {
 }
 
 /// This route always says hello.
+///
+/// The first paragraph of the doc comment is used as the summary of the route,
+/// while the following ones are used as its description.
 #[autoroute(GET, path="/hello", tags=["hello", "world"],
     responses=[
         (IM_A_TEAPOT, body=String, serializer=NONE, description="Always says hello"),
@@ -19,7 +22,7 @@ async fn hello_route() -> HelloRouteResponses {
     "Hello World!".to_string().into_im_a_teapot()
 }
 
-#[autoroute(GET, path="/no/description", tags=["hello", "world"],
+#[autoroute(GET, path="/no/description", tags=["hello", "world"], summary="Says hello without doc comment", trace_duration=true,
     responses=[
         (IM_A_TEAPOT, body=String, serializer=NONE),
     ]
@@ -39,21 +42,29 @@ async fn bonjour_secret_route() -> BonjourSecretRouteResponses {
 }
 
 /// This route is hidden.
-#[autoroute(GET, path="/secret/bye", tags=["bye", "secret"],
+#[autoroute(GET, path="/secret/bye", tags=["bye", "secret"], enum_name="ByeResponses",
     responses=[
         (IM_A_TEAPOT, body=String, serializer=NONE, description="Always says bye"),
     ]
 )]
-async fn bye_secret_route() -> ByeSecretRouteResponses {
+async fn bye_secret_route() -> ByeResponses {
     "Bye World!".to_string().into_im_a_teapot()
 }
 
 #[cfg(test)]
 mod test {
     use axum::http::{Method, StatusCode};
+    use axum::response::Response;
+    use axum_autoroute::{
+        AutorouteApiRouter, RegisteredRoute, RouteInfo, TagMergeStrategy, autoroute_test, method_router, method_routers,
+    };
     use tower::ServiceExt;
+    use utoipa::OpenApi;
+    use utoipa::openapi::ExternalDocs;
+    use utoipa::openapi::server::Server;
 
     use super::router;
+    use crate::OpenApiDoc;
     use crate::test_utils::*;
 
     #[tokio::test]
@@ -68,6 +79,277 @@ mod test {
         assert_traces!("hello.traces");
     }
 
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn no_description_duration() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/no/description"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+
+        // the duration varies between runs, so it cannot be checked against a reference file
+        #[cfg(all(feature = "tracing", not(feature = "tracing_json")))]
+        {
+            assert!(logs_contain("'no_description' finished -> 418:IM_A_TEAPOT"));
+            assert!(logs_contain("duration_ms="));
+        }
+        #[cfg(feature = "tracing_json")]
+        {
+            assert!(logs_contain("'no_description' finished status=418"));
+            assert!(logs_contain("duration duration_ms="));
+        }
+    }
+
+    #[tokio::test]
+    async fn private_routes_only() {
+        let (router, doc) = AutorouteApiRouter::<()>::new()
+            .with_priv_routes(method_routers!(super::bonjour_secret_route, super::bye_secret_route))
+            .split_for_parts();
+        assert!(doc.paths.paths.is_empty());
+
+        let response = router.oneshot(request_empty(Method::GET, "/secret/bye")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+        assert_eq!(response_to_str(response).await, "Bye World!");
+    }
+
+    #[tokio::test]
+    async fn hello_merge_private() {
+        let router = AutorouteApiRouter::new().merge_private(router());
+        assert!(router.routes().is_empty());
+        assert_eq!(router.private_routes().len(), 2);
+
+        let (router, doc) = router.split_for_parts();
+        assert!(doc.paths.paths.is_empty());
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/hello"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+
+        // private routes of the merged router are ignored
+        let response = router.oneshot(request_empty(Method::GET, "/secret/bye")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn hello_merge_with_prefix() {
+        let router = AutorouteApiRouter::new().merge_with_prefix("/api", router());
+        assert_eq!(
+            router.routes(),
+            [
+                RouteInfo::new(Method::GET, "/api/hello"),
+                RouteInfo::new(Method::GET, "/api/no/description")
+            ]
+        );
+        assert_eq!(
+            router.private_routes(),
+            [
+                RouteInfo::new(Method::GET, "/api/secret/bonjour"),
+                RouteInfo::new(Method::GET, "/api/secret/bye")
+            ]
+        );
+
+        let (_, doc) = router.clone().split_for_parts();
+        assert_eq!(
+            doc.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/hello", "/api/no/description"]
+        );
+        // the tags of the merged router are kept
+        assert!(doc.tags.unwrap().iter().any(|tag| tag.name == "hello"));
+
+        let (service, _) = router.split_for_parts();
+        for (uri, status) in [
+            ("/api/hello", StatusCode::IM_A_TEAPOT),
+            ("/api/secret/bye", StatusCode::IM_A_TEAPOT),
+            ("/hello", StatusCode::NOT_FOUND),
+        ] {
+            let response = service.clone().oneshot(request_empty(Method::GET, uri)).await.unwrap();
+            assert_eq!(response.status(), status, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn hello_nest_service() {
+        use axum::Router;
+        use axum::routing::get;
+
+        let service = Router::new().route("/file", get(|| async { "file content" }));
+        let router = router()
+            .nest_service("/static", service.clone(), true)
+            .nest_service("/legacy", service, false);
+        // the services do not appear in the documentation
+        let (service_router, doc) = router.clone().split_for_parts();
+        check_openapi("hello.openapi.json", &doc);
+        for uri in ["/static/file", "/legacy/file"] {
+            let response = service_router
+                .clone()
+                .oneshot(request_empty(Method::GET, uri))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response_to_str(response).await, "file content");
+        }
+
+        // only the public service is kept when merged as private
+        let (router, _) = AutorouteApiRouter::new().merge_private(router).split_for_parts();
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/static/file"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = router
+            .oneshot(request_empty(Method::GET, "/legacy/file"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn hello_openapi_uis() {
+        let router = router();
+        let uis = router
+            .serve_redoc("/redoc", "/redoc/openapi.json")
+            .merge(router.serve_rapidoc("/rapidoc", "/rapidoc/openapi.json"))
+            .merge(router.serve_scalar("/scalar", "/scalar/openapi.json"));
+        let (router, doc) = router.split_for_parts();
+        let router = router.merge(uis);
+
+        for ui in ["redoc", "rapidoc", "scalar"] {
+            let response = router
+                .clone()
+                .oneshot(request_empty(Method::GET, &format!("/{ui}")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert!(response_to_str(response).await.contains("<html"));
+
+            // only the public routes are documented
+            let response = router
+                .clone()
+                .oneshot(request_empty(Method::GET, &format!("/{ui}/openapi.json")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response_to_json(response).await, serde_json::to_value(&doc).unwrap());
+        }
+
+        // the routes of the api are still served
+        let response = router.oneshot(request_empty(Method::GET, "/hello")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[tokio::test]
+    async fn hello_into_from_parts() {
+        let (pub_router, priv_router) = router().into_parts();
+        assert_eq!(pub_router.get_openapi().paths.paths.len(), 2);
+        assert_eq!(priv_router.get_openapi().paths.paths.len(), 2);
+
+        let (router, doc) = AutorouteApiRouter::from_parts(pub_router, priv_router).split_for_parts();
+        check_openapi("hello.openapi.json", &doc);
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/hello"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+        let response = router.oneshot(request_empty(Method::GET, "/secret/bye")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[tokio::test]
+    async fn hello_from_openapi_router() {
+        let (pub_router, priv_router) = router().into_parts();
+
+        // a single router is entirely public
+        let (router, doc) = AutorouteApiRouter::from(pub_router.clone()).split_for_parts();
+        check_openapi("hello.openapi.json", &doc);
+        let response = router.oneshot(request_empty(Method::GET, "/hello")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+
+        // a pair of routers is split into public and private routes
+        let (router, doc) = AutorouteApiRouter::from((pub_router, priv_router)).split_for_parts();
+        check_openapi("hello.openapi.json", &doc);
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/hello"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+        let response = router.oneshot(request_empty(Method::GET, "/secret/bye")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[tokio::test]
+    async fn hello_with_prefix() {
+        let router = router().with_prefix("/prefix");
+        assert_eq!(router.routes()[0].path(), "/prefix/hello");
+        assert_eq!(router.private_routes()[0].path(), "/prefix/secret/bonjour");
+
+        let (router, doc) = router.split_for_parts();
+        assert_eq!(doc.info.title, OpenApiDoc::openapi().info.title);
+        assert!(doc.paths.paths.contains_key("/prefix/hello"));
+        assert!(!doc.paths.paths.contains_key("/hello"));
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/prefix/hello"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+        assert_eq!(response_to_str(response).await, "Hello World!");
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/prefix/secret/bye"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+
+        let response = router.oneshot(request_empty(Method::GET, "/hello")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn hello_scope() {
+        let router: AutorouteApiRouter = AutorouteApiRouter::scope("/api/v2")
+            .with_pub_routes(method_routers!(super::hello_route, super::no_description))
+            .with_priv_route(method_router!(super::bye_secret_route))
+            .into();
+        assert_eq!(
+            router.routes().iter().map(RegisteredRoute::path).collect::<Vec<_>>(),
+            ["/api/v2/hello", "/api/v2/no/description"]
+        );
+        assert_eq!(router.private_routes()[0].path(), "/api/v2/secret/bye");
+
+        let (router, doc) = router.split_for_parts();
+        assert!(doc.paths.paths.contains_key("/api/v2/hello"));
+        assert!(!doc.paths.paths.contains_key("/api/v2/secret/bye"));
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/api/v2/secret/bye"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+
+        let response = router.oneshot(request_empty(Method::GET, "/hello")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn hello_router_debug() {
+        assert_eq!(
+            format!("{:?}", router()),
+            r#"AutorouteApiRouter { pub_routes: ["GET /hello", "GET /no/description"], priv_routes: ["GET /secret/bonjour", "GET /secret/bye"], .. }"#
+        );
+    }
+
     #[test]
     fn hello_openapi() {
         let (_, doc) = router().split_for_parts();
@@ -75,4 +357,270 @@ mod test {
         let (_, doc) = router().split_for_parts_with_private_doc();
         check_openapi("hello.openapi.private.json", &doc);
     }
+
+    #[test]
+    fn hello_servers() {
+        let router = router()
+            .add_server("https://api.example.com", Some("Production"))
+            .add_server("http://localhost:3000", None);
+
+        let (_, doc) = router.clone().split_for_parts();
+        let servers = doc.servers.unwrap();
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers[0].url, "https://api.example.com");
+        assert_eq!(servers[0].description.as_deref(), Some("Production"));
+        assert_eq!(servers[1].url, "http://localhost:3000");
+        assert_eq!(servers[1].description, None);
+
+        // servers are not duplicated when private routes are documented too
+        let (_, doc) = router.split_for_parts_with_private_doc();
+        assert_eq!(doc.servers.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn hello_split_for_parts_with_base_url() {
+        let (_, doc) = router().split_for_parts_with_base_url("http://localhost:9100");
+        let servers = doc.servers.unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].url, "http://localhost:9100");
+        assert_eq!(servers[0].description, None);
+
+        // the base url comes before the declared servers
+        let (_, doc) = router()
+            .add_server("https://api.example.com", Some("Production"))
+            .split_for_parts_with_base_url("http://localhost:9100");
+        let servers = doc.servers.unwrap();
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers[0].url, "http://localhost:9100");
+        assert_eq!(servers[1].url, "https://api.example.com");
+    }
+
+    #[test]
+    fn hello_no_duplicate_operation_ids() {
+        router().assert_no_duplicate_operation_ids();
+
+        // private routes are not checked
+        let router = router().merge_private(router().with_prefix("/private"));
+        router.assert_no_duplicate_operation_ids();
+    }
+
+    #[test]
+    fn hello_map_openapi() {
+        // the closure is applied to the documentation of the private routes too
+        let (_, doc) = router()
+            .map_openapi(|doc| {
+                for path_item in doc.paths.paths.values_mut() {
+                    path_item.summary = Some("Mapped".to_string());
+                }
+            })
+            .split_for_parts_with_private_doc();
+        assert_eq!(doc.paths.paths.len(), 4);
+        assert!(
+            doc.paths
+                .paths
+                .values()
+                .all(|path_item| path_item.summary.as_deref() == Some("Mapped"))
+        );
+    }
+
+    #[test]
+    fn hello_openapi_base_path() {
+        // without server, the base path is declared as a relative server url
+        let (_, doc) = router().with_openapi_base_path("/api/v2/").split_for_parts();
+        let servers = doc.servers.unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].url, "/api/v2");
+        assert_eq!(doc.paths.paths.keys().next().unwrap(), "/hello");
+
+        // otherwise, it is appended to the declared servers
+        let router = router()
+            .add_server("https://api.example.com/", Some("Production"))
+            .add_server("http://localhost:3000", None)
+            .with_openapi_base_path("/api/v2");
+        let (_, doc) = router.split_for_parts_with_private_doc();
+        let servers = doc.servers.unwrap();
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers[0].url, "https://api.example.com/api/v2");
+        assert_eq!(servers[0].description.as_deref(), Some("Production"));
+        assert_eq!(servers[1].url, "http://localhost:3000/api/v2");
+
+        // the private routes are documented under the same base path as the public ones
+        let mut openapi = OpenApiDoc::openapi();
+        openapi.servers = Some(vec![Server::new("https://api.example.com")]);
+        let router: AutorouteApiRouter = AutorouteApiRouter::new_with_openapi(openapi)
+            .with_priv_routes(method_routers!(super::bye_secret_route))
+            .with_openapi_base_path("/api/v2");
+        let (_, doc) = router.split_for_parts_with_private_doc();
+        assert_eq!(
+            doc.servers
+                .unwrap()
+                .iter()
+                .map(|server| server.url.as_str())
+                .collect::<Vec<_>>(),
+            ["https://api.example.com/api/v2"]
+        );
+    }
+
+    #[test]
+    fn hello_contact_license() {
+        let router = router()
+            .set_contact("API team", Some("https://example.com/contact"), Some("api@example.com"))
+            .set_license("MIT", Some("https://opensource.org/licenses/MIT"));
+
+        let (_, doc) = router.clone().split_for_parts();
+        let contact = doc.info.contact.unwrap();
+        assert_eq!(contact.name.as_deref(), Some("API team"));
+        assert_eq!(contact.url.as_deref(), Some("https://example.com/contact"));
+        assert_eq!(contact.email.as_deref(), Some("api@example.com"));
+        let license = doc.info.license.unwrap();
+        assert_eq!(license.name, "MIT");
+        assert_eq!(license.url.as_deref(), Some("https://opensource.org/licenses/MIT"));
+        // the rest of the info is kept
+        assert_eq!(doc.info.title, OpenApiDoc::openapi().info.title);
+
+        let (_, doc) = router
+            .set_contact("Other team", None, None)
+            .split_for_parts_with_private_doc();
+        let contact = doc.info.contact.unwrap();
+        assert_eq!(contact.name.as_deref(), Some("Other team"));
+        assert_eq!(contact.url, None);
+        assert_eq!(contact.email, None);
+        assert_eq!(doc.info.license.unwrap().name, "MIT");
+    }
+
+    #[test]
+    fn hello_define_tag() {
+        let (_, doc) = router()
+            .define_tag(
+                "greetings",
+                Some("Routes saying hello"),
+                Some(ExternalDocs::new("https://example.com/greetings")),
+            )
+            .define_tag("hello", Some("Replaced description"), None)
+            .split_for_parts();
+
+        // the tags of `OpenApiDoc` are kept
+        let tags = doc.tags.unwrap();
+        assert_eq!(
+            tags.iter().map(|tag| tag.name.as_str()).collect::<Vec<_>>(),
+            ["hello", "world", "greetings"]
+        );
+        assert_eq!(tags[0].description.as_deref(), Some("Replaced description"));
+        assert_eq!(tags[2].description.as_deref(), Some("Routes saying hello"));
+        assert_eq!(
+            tags[2].external_docs.as_ref().map(|docs| docs.url.as_str()),
+            Some("https://example.com/greetings")
+        );
+
+        // the tags are also replaced in the documentation of the private routes
+        let (_, doc) = AutorouteApiRouter::new()
+            .merge_private(router())
+            .define_tag("hello", Some("Replaced description"), None)
+            .split_for_parts_with_private_doc();
+        assert_eq!(
+            doc.tags
+                .unwrap()
+                .into_iter()
+                .filter(|tag| tag.name == "hello")
+                .map(|tag| tag.description)
+                .collect::<Vec<_>>(),
+            [Some("Replaced description".to_string())]
+        );
+    }
+
+    #[test]
+    fn hello_with_tags() {
+        let router = router().with_tags(&["v2", "world"]);
+
+        let (_, doc) = router.clone().split_for_parts();
+        let hello = doc.paths.paths["/hello"].get.as_ref().unwrap();
+        assert_eq!(
+            hello.tags.as_deref(),
+            Some(&["hello", "world", "v2"].map(String::from)[..])
+        );
+        let no_description = doc.paths.paths["/no/description"].get.as_ref().unwrap();
+        assert_eq!(
+            no_description.tags.as_deref(),
+            Some(&["hello", "world", "v2"].map(String::from)[..])
+        );
+
+        // the tags are also applied to the private routes
+        let (_, doc) = router.split_for_parts_with_private_doc();
+        assert!(
+            doc.paths
+                .paths
+                .values()
+                .filter_map(|item| item.get.as_ref())
+                .all(|operation| operation.tags.as_ref().unwrap().contains(&"v2".to_string()))
+        );
+    }
+
+    #[autoroute_test(handler=super::hello_route, expected_status=IM_A_TEAPOT)]
+    #[tokio::test]
+    async fn hello_autoroute_test(response: Response) {
+        assert_eq!(response_to_str(response).await, "Hello World!");
+    }
+
+    #[autoroute_test(method=GET, path="/unknown", expected_status=404)]
+    #[tokio::test]
+    async fn hello_autoroute_test_not_found() {}
+
+    fn tag_description(router: AutorouteApiRouter, name: &str) -> Vec<Option<String>> {
+        let (_, doc) = router.split_for_parts();
+        doc.tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|tag| tag.name == name)
+            .map(|tag| tag.description)
+            .collect()
+    }
+
+    #[test]
+    fn hello_tag_merge_strategy() {
+        let other = || AutorouteApiRouter::new().define_tag("hello", Some("Other description"), None);
+        let description = OpenApiDoc::openapi()
+            .tags
+            .unwrap()
+            .into_iter()
+            .find(|tag| tag.name == "hello")
+            .unwrap()
+            .description;
+
+        // identical tags are kept only once
+        let router_identical = router().merge(AutorouteApiRouter::new_with_openapi(OpenApiDoc::openapi()));
+        assert_eq!(tag_description(router_identical, "hello"), vec![description.clone()]);
+        // by default, the first tag is kept
+        assert_eq!(tag_description(router().merge(other()), "hello"), vec![description]);
+        let router_overwrite = router()
+            .with_tag_merge_strategy(TagMergeStrategy::Overwrite)
+            .nest("/other", other());
+        assert_eq!(
+            tag_description(router_overwrite, "hello"),
+            [Some("Other description".to_string())]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "tag `hello` is declared with different definitions")]
+    fn hello_tag_merge_strategy_error() {
+        let other = AutorouteApiRouter::new().define_tag("hello", Some("Other description"), None);
+        let _ = router().with_tag_merge_strategy(TagMergeStrategy::Error).merge(other);
+    }
+
+    #[test]
+    fn hello_openapi_string() {
+        let router = router();
+        let json = router.openapi_json().unwrap();
+        let (_, doc) = router.split_for_parts();
+        assert_eq!(json, doc.to_pretty_json().unwrap());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn hello_openapi_yaml() {
+        let yaml = router().openapi_yaml().unwrap();
+        assert!(yaml.starts_with("openapi: 3.1.0\n"));
+        assert!(yaml.contains("/hello:"));
+        assert!(!yaml.contains("/secret/bonjour:"));
+    }
 }
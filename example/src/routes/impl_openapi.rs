@@ -0,0 +1,110 @@
+use axum::extract::Query;
+use axum_autoroute::{AutorouteApiRouter, autoroute, impl_openapi_for, method_routers};
+use serde::{Deserialize, Serialize};
+use utoipa::PartialSchema;
+use utoipa::openapi::path::{ParameterBuilder, ParameterIn};
+use utoipa::openapi::{ObjectBuilder, Required, Type};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(invert_color))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+/// A color serialized as an hexadecimal string (e.g. `#ff8000`).
+/// Deriving `ToSchema` would document it as an array of integers.
+struct Rgb(u8, u8, u8);
+
+impl From<Rgb> for String {
+    fn from(Rgb(r, g, b): Rgb) -> Self {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let parse = |range| {
+            value
+                .get(range)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or(format!("invalid color: {value}"))
+        };
+        if value.len() != 7 || !value.starts_with('#') {
+            return Err(format!("invalid color: {value}"));
+        }
+        Ok(Self(parse(1..3)?, parse(3..5)?, parse(5..7)?))
+    }
+}
+
+impl_openapi_for!(Rgb as "Rgb", schema = ObjectBuilder::new()
+    .schema_type(Type::String)
+    .pattern(Some("^#[0-9a-f]{6}$"))
+    .description(Some("A color as an hexadecimal string"))
+    .build());
+
+#[derive(Debug, Deserialize)]
+struct ColorQuery {
+    color: Rgb,
+}
+
+impl_openapi_for!(
+    ColorQuery,
+    params = [ParameterBuilder::new()
+        .name("color")
+        .parameter_in(ParameterIn::Query)
+        .required(Required::True)
+        .description(Some("The color to invert"))
+        .schema(Some(Rgb::schema()))
+        .build()]
+);
+
+/// Returns the inverse of the provided color
+#[autoroute(GET, path="/color/invert", tags=["impl_openapi"],
+    responses=[
+        (OK, body=Rgb, description="The inverted color"),
+    ]
+)]
+async fn invert_color(Query(query): Query<ColorQuery>) -> InvertColorResponses {
+    let Rgb(r, g, b) = query.color;
+    Rgb(!r, !g, !b).into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use tower::Service;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn invert_color() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/color/invert?color=%23ff8000"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!("#007fff"));
+
+        let response = service
+            .call(request_empty(Method::GET, "/color/invert?color=red"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        assert_traces!("invert_color.traces");
+    }
+
+    #[test]
+    fn impl_openapi_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("impl_openapi.openapi.json", &doc);
+    }
+}
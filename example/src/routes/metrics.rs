@@ -0,0 +1,113 @@
+use axum::extract::Path;
+use axum_autoroute::{AutorouteApiRouter, MetricsConfig, autoroute, method_router};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(metrics_item))
+        .with_prometheus_metrics("/metrics")
+}
+
+/// Same routes, with custom metric and label names
+pub fn router_custom_config() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(metrics_item))
+        .with_prometheus_metrics_config(
+            "/custom/metrics",
+            MetricsConfig::new()
+                .with_prefix("example")
+                .with_label_names("verb", "route", "code")
+                .with_buckets(&[1.0, 0.1]),
+        )
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct ItemParam {
+    /// The id of the item
+    id: u32,
+}
+
+/// The requests to this route are counted in the prometheus metrics
+#[autoroute(GET, path="/metrics/items/{id}", tags=["metrics"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The item exists"),
+        (NOT_FOUND, body=String, serializer=NONE, description="The item does not exist"),
+    ]
+)]
+async fn metrics_item(Path(param): Path<ItemParam>) -> MetricsItemResponses {
+    if param.id < 10 {
+        format!("item {}", param.id).into_ok()
+    } else {
+        "unknown item".to_string().into_not_found()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode, header};
+    use tower::Service;
+
+    use super::{router, router_custom_config};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn prometheus_metrics() {
+        let (mut router, doc) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        for uri in ["/metrics/items/1", "/metrics/items/2", "/metrics/items/12", "/unknown"] {
+            service.call(request_empty(Method::GET, uri)).await.unwrap();
+        }
+
+        let response = service.call(request_empty(Method::GET, "/metrics")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+        let metrics = response_to_str(response).await;
+        let lines: Vec<_> = metrics.lines().collect();
+        assert!(lines.contains(&"http_requests_total{method=\"GET\",path=\"/metrics/items/{id}\",status=\"200\"} 2"));
+        assert!(lines.contains(&"http_requests_total{method=\"GET\",path=\"/metrics/items/{id}\",status=\"404\"} 1"));
+        assert!(
+            lines.contains(&"http_request_errors_total{method=\"GET\",path=\"/metrics/items/{id}\",status=\"404\"} 1")
+        );
+        assert!(lines.contains(
+            &"http_request_duration_seconds_bucket{method=\"GET\",path=\"/metrics/items/{id}\",le=\"+Inf\"} 3"
+        ));
+        assert!(lines.contains(&"http_request_duration_seconds_count{method=\"GET\",path=\"/metrics/items/{id}\"} 3"));
+        // the unmatched requests and the metrics endpoint itself are not recorded
+        assert!(!metrics.contains("/unknown"));
+        assert!(!metrics.contains("path=\"/metrics\""));
+
+        // the metrics endpoint is private
+        assert!(!doc.paths.paths.contains_key("/metrics"));
+    }
+
+    #[tokio::test]
+    async fn prometheus_metrics_custom_config() {
+        let (mut router, _) = router_custom_config().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        service
+            .call(request_empty(Method::GET, "/metrics/items/1"))
+            .await
+            .unwrap();
+
+        let response = service
+            .call(request_empty(Method::GET, "/custom/metrics"))
+            .await
+            .unwrap();
+        let metrics = response_to_str(response).await;
+        let lines: Vec<_> = metrics.lines().collect();
+        assert!(lines.contains(&"example_requests_total{verb=\"GET\",route=\"/metrics/items/{id}\",code=\"200\"} 1"));
+        // the buckets are sorted
+        let buckets: Vec<_> = lines
+            .iter()
+            .filter(|line| line.starts_with("example_request_duration_seconds_bucket"))
+            .map(|line| line.split("le=").nth(1).unwrap())
+            .collect();
+        assert_eq!(buckets, ["\"0.1\"} 1", "\"1\"} 1", "\"+Inf\"} 1"]);
+    }
+}
@@ -1,13 +1,34 @@
+pub mod body_form;
 pub mod body_json;
+pub mod body_json_lines;
 pub mod body_multipart;
 pub mod body_raw;
+pub mod callbacks;
+pub mod common_schema;
+pub mod cors;
 pub mod custom_extractor;
+pub mod doc_markdown;
 pub mod hello;
+pub mod impl_openapi;
 pub mod main_example;
+pub mod metrics;
+pub mod module_openapi;
+pub mod multiple_methods;
 pub mod params_path;
 pub mod params_query;
+pub mod parts_extractors;
+pub mod request_timeout;
 pub mod response_cookie;
+pub mod response_custom_status;
+pub mod response_default;
 pub mod response_file;
 pub mod response_json;
+pub mod response_paginated;
+pub mod response_problem;
+pub mod response_range;
+pub mod response_result;
+#[cfg(feature = "yaml")]
+pub mod response_yaml;
 pub mod route_info;
+pub mod security;
 pub mod state;
@@ -0,0 +1,80 @@
+use axum_autoroute::{AutorouteApiRouter, method_router};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(items::list_items))
+        .with_pub_route(method_router!(items::get_first_item))
+        .with_pub_route(method_router!(items::archive::list_archived_items))
+}
+
+/// All the handlers of this module (and of its nested modules) are tagged with `items`.
+#[axum_autoroute::module_openapi(tags=["items"])]
+pub mod items {
+    use axum_autoroute::autoroute;
+
+    /// List the items.
+    #[autoroute(GET, path="/module/items",
+        responses=[
+            (OK, body=Vec<String>),
+        ]
+    )]
+    pub async fn list_items() -> ListItemsResponses {
+        vec!["first".to_string(), "second".to_string()].into_ok()
+    }
+
+    /// Get the first item.
+    #[autoroute(GET, path="/module/items/first", tags=["details", "items"],
+        responses=[
+            (OK, body=String, serializer=NONE),
+        ]
+    )]
+    pub async fn get_first_item() -> GetFirstItemResponses {
+        "first".to_string().into_ok()
+    }
+
+    #[axum_autoroute::module_openapi(tags=["archive"])]
+    pub mod archive {
+        use axum_autoroute::autoroute;
+
+        /// List the archived items.
+        #[autoroute(GET, path="/module/items/archived",
+            responses=[
+                (OK, body=Vec<String>),
+            ],
+        )]
+        pub async fn list_archived_items() -> ListArchivedItemsResponses {
+            Vec::<String>::new().into_ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn module_openapi() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/module/items/first"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "first");
+    }
+
+    #[test]
+    fn module_openapi_tags() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("module_openapi.openapi.json", &doc);
+
+        let tags = |path: &str| doc.paths.paths[path].get.as_ref().unwrap().tags.clone().unwrap();
+        assert_eq!(tags("/module/items"), ["items"]);
+        assert_eq!(tags("/module/items/first"), ["items", "details"]);
+        assert_eq!(tags("/module/items/archived"), ["items", "archive"]);
+    }
+}
@@ -0,0 +1,81 @@
+use axum::http::Method;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(multiple_methods))
+}
+
+/// This route can be called either with GET or POST.
+#[autoroute(methods=[GET, POST], path="/methods", tags=["methods"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Returns the method used"),
+    ]
+)]
+async fn multiple_methods(method: Method) -> MultipleMethodsResponses {
+    format!("Called with {method}").into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use axum_autoroute::route_info;
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn multiple_methods() {
+        let (router, _) = router().split_for_parts();
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/methods"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "Called with GET");
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::POST, "/methods"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "Called with POST");
+
+        let response = router.oneshot(request_empty(Method::PUT, "/methods")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    fn multiple_methods_route_info() {
+        assert_eq!(route_info!(super::multiple_methods).method(), Method::GET);
+        assert_eq!(router().routes().len(), 2);
+    }
+
+    #[test]
+    fn multiple_methods_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("multiple_methods.openapi.json", &doc);
+    }
+
+    #[test]
+    fn multiple_methods_validate_openapi() {
+        // the operation is shared by both methods
+        assert_eq!(
+            router().validate_openapi(),
+            Err(vec![
+                "operationId 'multiple_methods' is used by several operations: GET /methods, POST /methods".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "duplicated operationId found:\noperationId 'multiple_methods' is used by several operations: GET /methods, POST /methods"
+    )]
+    fn multiple_methods_duplicate_operation_ids() {
+        router().assert_no_duplicate_operation_ids();
+    }
+}
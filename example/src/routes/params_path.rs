@@ -10,6 +10,7 @@ pub fn router() -> AutorouteApiRouter {
         two_params_reverse,
         bad_path_param,
         multi_path_extractors,
+        tuple_path_params,
     ))
 }
 
@@ -24,10 +25,10 @@ struct ParamsEven {
 #[autoroute(GET, path="/path/{number}", tags=["path"],
     responses=[
         (OK, body=u32, description="Returns the provided number if it is even"),
-        (406, body=String, serializer=NONE, description="Returns an error if the provided number is odd"),
+        (406, body=String, serializer=NONE, trace_level=warn, description="Returns an error if the provided number is odd"),
     ]
 )]
-async fn path_params_even(Path(path): Path<ParamsEven>) -> PathParamsEvenResponses {
+async fn path_params_even(#[extractor(trace_level=info)] Path(path): Path<ParamsEven>) -> PathParamsEvenResponses {
     let ParamsEven { number } = path;
     if number % 2 == 0 {
         number.into_200()
@@ -43,7 +44,7 @@ struct TwoParams {
 }
 
 /// This route parse two params (number then string)
-#[autoroute(GET, path="/path/{id}/{name}", tags=["path"],
+#[autoroute(GET, path="/path/{id}/{name}", tags=["path"], auto_document_rejections=true,
     responses=[
         (OK, body=TwoParams, description="Returns the provided parameters"),
     ]
@@ -63,7 +64,7 @@ async fn two_params_reverse(Path(params): Path<TwoParams>) -> TwoParamsReverseRe
 }
 
 /// This route tries to extract a params that does not exists, it will fail at runtime
-#[autoroute(GET, path="/bad/path/{wrong}", tags=["path"],
+#[autoroute(GET, path="/bad/path/{wrong}", tags=["path"], auto_document_rejections=true,
     responses=[
         (OK, body=String, description="Always return OK"),
     ]
@@ -87,6 +88,17 @@ async fn multi_path_extractors(
     (params1, params2).into_ok()
 }
 
+/// This route extracts its path params as a tuple, documented as positional path parameters
+#[autoroute(GET, path="/tuple/path/{id}/{name}", tags=["path"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Return the values extracted from path"),
+    ]
+)]
+async fn tuple_path_params(Path(params): Path<(u32, String)>) -> TuplePathParamsResponses {
+    let (id, name) = params;
+    format!("{name} has id {id}").into_ok()
+}
+
 #[cfg(test)]
 mod test {
     use axum::http::{Method, StatusCode};
@@ -190,6 +202,28 @@ mod test {
         assert_traces!("multi_path_extractors.traces");
     }
 
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn tuple_path_params() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/tuple/path/9/test"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "test has id 9");
+
+        let response = service
+            .call(request_empty(Method::GET, "/tuple/path/test/9"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        assert_traces!("tuple_path_params.traces");
+    }
+
     #[tokio::test]
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     async fn runtime_path_extract_failure() {
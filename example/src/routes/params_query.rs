@@ -4,7 +4,13 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 pub fn router() -> AutorouteApiRouter {
-    AutorouteApiRouter::new().with_pub_routes(method_routers!(query_params1, query_params2, query_params3))
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(
+        query_params1,
+        query_params2,
+        query_params3,
+        query_params_optional,
+        query_params_list
+    ))
 }
 
 #[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
@@ -27,6 +33,24 @@ struct QueryParam3 {
     list: QueryEnum,
 }
 
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+/// Optional query parameters, `Option` fields are documented as not required and nullable
+struct QueryParamOptional {
+    /// An optional name
+    name: Option<String>,
+    /// An optional limit
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+/// List query parameters, provided by repeating the query key (e.g. `?ids=1&ids=2`)
+struct QueryParamList {
+    /// A list of identifiers
+    ids: Vec<u32>,
+    /// An optional list of names
+    names: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 enum QueryEnum {
     Val1,
@@ -47,7 +71,8 @@ async fn query_params1(Query(query1): Query<QueryParam1>) -> QueryParams1Respons
 #[autoroute(GET, path="/query2", tags=["query"],
     responses=[
         (OK, body=((QueryParam1, QueryParam2)), description="Returns the provided parameters"),
-    ]
+    ],
+    params_description=[("number", "A signed number"), ("id", "The identifier of the item")],
 )]
 async fn query_params2(
     #[extractor(trace = false)] Query(query1): Query<QueryParam1>,
@@ -66,15 +91,70 @@ async fn query_params3(Query(query3): Query<QueryParam3>) -> QueryParams3Respons
     (format!("{:?}", query3.list)).into_ok()
 }
 
+/// This route parses optional query params
+#[autoroute(GET, path="/query/optional", tags=["query"],
+    responses=[
+        (OK, body=QueryParamOptional, description="Returns the provided optional query params"),
+    ]
+)]
+async fn query_params_optional(Query(query): Query<QueryParamOptional>) -> QueryParamsOptionalResponses {
+    query.into_ok()
+}
+
+/// This route parses list query params
+///
+/// The `axum_extra` `Query` extractor is used as the axum one does not support repeated keys.
+#[autoroute(GET, path="/query/list", tags=["query"],
+    responses=[
+        (OK, body=QueryParamList, description="Returns the provided list query params"),
+    ]
+)]
+async fn query_params_list(
+    axum_extra::extract::Query(query): axum_extra::extract::Query<QueryParamList>,
+) -> QueryParamsListResponses {
+    query.into_ok()
+}
+
 #[cfg(test)]
 mod test {
+    use axum::extract::Query;
     use axum::http::{Method, StatusCode};
+    use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
     use serde_json::json;
     use tower::Service;
 
-    use super::router;
+    use super::{QueryParam1, router};
     use crate::test_utils::*;
 
+    #[test]
+    fn query_params_unknown_description() {
+        /// This route describes a parameter which is not declared by its query params
+        #[autoroute(GET, path="/query/unknown", tags=["query"],
+            responses=[
+                (OK, body=QueryParam1, description="Returns the provided query params"),
+            ],
+            params_description=[("id", "The identifier of the item"), ("unknown", "Not a parameter of the route")],
+        )]
+        async fn query_params_unknown(Query(query): Query<QueryParam1>) -> QueryParamsUnknownResponses {
+            query.into_ok()
+        }
+
+        // the unknown parameter is ignored instead of panicking when generating the openapi documentation
+        let router: AutorouteApiRouter = AutorouteApiRouter::new().with_pub_route(method_router!(query_params_unknown));
+        let (_, doc) = router.split_for_parts();
+        let operation = doc.paths.paths["/query/unknown"].get.as_ref().unwrap();
+        let descriptions: Vec<_> = operation
+            .parameters
+            .iter()
+            .flatten()
+            .map(|parameter| (parameter.name.as_str(), parameter.description.as_deref()))
+            .collect();
+        assert_eq!(
+            descriptions,
+            [("id", Some("The identifier of the item")), ("str", Some("A string"))]
+        );
+    }
+
     #[tokio::test]
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     async fn query_params1() {
@@ -146,6 +226,74 @@ mod test {
         assert_traces!("query_params3.traces");
     }
 
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn query_params_optional() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/optional?name=test&limit=5"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!({"name": "test", "limit": 5}));
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/optional?limit=2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!({"name": null, "limit": 2}));
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/optional"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!({"name": null, "limit": null}));
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/optional?limit=abc"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        assert_traces!("query_params_optional.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn query_params_list() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/list?ids=1&ids=2&names=a&ids=3"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"ids": [1, 2, 3], "names": ["a"]})
+        );
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/list?ids=4"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!({"ids": [4], "names": null}));
+
+        let response = service
+            .call(request_empty(Method::GET, "/query/list?ids=4&ids=abc"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        assert_traces!("query_params_list.traces");
+    }
+
     #[test]
     fn params_query_openapi() {
         let (_, doc) = router().split_for_parts();
@@ -0,0 +1,365 @@
+// `axum_extra::extract::Host` is deprecated but is still supported by autoroute
+#![allow(deprecated)]
+
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, Extension, OriginalUri, RawPathParams};
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_routers};
+use axum_extra::extract::cookie::Key;
+use axum_extra::extract::{CookieJar, Host, PrivateCookieJar, SignedCookieJar};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_routes(method_routers!(
+        parts_original_uri,
+        parts_connect_info,
+        parts_host,
+        parts_extension,
+        parts_cookie_jar,
+        parts_raw_path_params
+    ))
+}
+
+/// Routes reading signed and private cookies, the key used to verify/decrypt them being provided as state
+pub fn router_cookie_key(key: Key) -> AutorouteApiRouter {
+    AutorouteApiRouter::<Key>::new()
+        .with_pub_routes(method_routers!(parts_signed_cookie_jar, parts_private_cookie_jar))
+        .with_state(key)
+}
+
+/// Returns the uri of the request as received by the server
+#[autoroute(GET, path="/parts/original_uri", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The original uri"),
+    ]
+)]
+async fn parts_original_uri(OriginalUri(uri): OriginalUri) -> PartsOriginalUriResponses {
+    uri.to_string().into_ok()
+}
+
+/// Returns the address of the client
+#[autoroute(GET, path="/parts/connect_info", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The client address"),
+    ]
+)]
+async fn parts_connect_info(ConnectInfo(addr): ConnectInfo<SocketAddr>) -> PartsConnectInfoResponses {
+    addr.to_string().into_ok()
+}
+
+/// Returns the host of the request
+#[autoroute(GET, path="/parts/host", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The host"),
+    ]
+)]
+async fn parts_host(Host(host): Host) -> PartsHostResponses {
+    host.into_ok()
+}
+
+/// The user authenticated by a middleware
+#[derive(Debug, Clone)]
+pub struct CurrentUser {
+    pub name: String,
+}
+
+/// Returns the name of the user inserted in the request extensions
+#[autoroute(GET, path="/parts/extension", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The user name"),
+    ]
+)]
+async fn parts_extension(Extension(user): Extension<CurrentUser>) -> PartsExtensionResponses {
+    user.name.into_ok()
+}
+
+/// Returns the value of the `session` cookie sent by the client
+#[autoroute(GET, path="/parts/cookie_jar", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The value of the session cookie"),
+        (NOT_FOUND, body=String, serializer=NONE, description="No session cookie was sent"),
+    ]
+)]
+async fn parts_cookie_jar(cookie_jar: CookieJar) -> PartsCookieJarResponses {
+    match cookie_jar.get("session") {
+        Some(cookie) => cookie.value().to_string().into_ok(),
+        None => "no session".to_string().into_not_found(),
+    }
+}
+
+/// Returns the value of the `session` cookie sent by the client, if its signature is valid
+#[autoroute(GET, path="/parts/signed_cookie_jar", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The value of the session cookie"),
+        (NOT_FOUND, body=String, serializer=NONE, description="No valid session cookie was sent"),
+    ]
+)]
+async fn parts_signed_cookie_jar(cookie_jar: SignedCookieJar) -> PartsSignedCookieJarResponses {
+    match cookie_jar.get("session") {
+        Some(cookie) => cookie.value().to_string().into_ok(),
+        None => "no session".to_string().into_not_found(),
+    }
+}
+
+/// Returns the decrypted value of the `session` cookie sent by the client
+#[autoroute(GET, path="/parts/private_cookie_jar", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The value of the session cookie"),
+        (NOT_FOUND, body=String, serializer=NONE, description="No valid session cookie was sent"),
+    ]
+)]
+async fn parts_private_cookie_jar(cookie_jar: PrivateCookieJar) -> PartsPrivateCookieJarResponses {
+    match cookie_jar.get("session") {
+        Some(cookie) => cookie.value().to_string().into_ok(),
+        None => "no session".to_string().into_not_found(),
+    }
+}
+
+/// Returns the path parameters of the request, without deserializing them
+#[autoroute(GET, path="/parts/raw_path_params/{kind}/{id}", tags=["parts"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The path parameters, as `key=value` pairs"),
+    ]
+)]
+async fn parts_raw_path_params(params: RawPathParams) -> PartsRawPathParamsResponses {
+    params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+        .into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+
+    use axum::body::Body;
+    use axum::extract::connect_info::MockConnectInfo;
+    use axum::extract::{Extension, Request};
+    use axum::http::header::{COOKIE, HOST, SET_COOKIE};
+    use axum::http::{Method, StatusCode};
+    use axum::response::IntoResponse;
+    use axum_autoroute::AutorouteApiRouter;
+    use axum_extra::extract::cookie::{Cookie, Key};
+    use axum_extra::extract::{PrivateCookieJar, SignedCookieJar};
+    use tower::ServiceExt;
+
+    use super::{CurrentUser, router, router_cookie_key};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_original_uri() {
+        let (router, _) = AutorouteApiRouter::new().nest("/nested", router()).split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/nested/parts/original_uri?a=b"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "/nested/parts/original_uri?a=b");
+
+        assert_traces!("parts_original_uri.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_connect_info() {
+        let (router, _) = router().split_for_parts();
+        let router = router.layer(MockConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4242))));
+        let response = router
+            .oneshot(request_empty(Method::GET, "/parts/connect_info"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "10.0.0.1:4242");
+
+        assert_traces!("parts_connect_info.traces");
+    }
+
+    #[tokio::test]
+    async fn parts_into_make_service() {
+        use tower::Service;
+
+        let client_addr = SocketAddr::from(([10, 0, 0, 2], 4343));
+        let service = router()
+            .into_make_service_with_connect_info::<SocketAddr>()
+            .call(client_addr)
+            .await
+            .unwrap();
+        let response = service
+            .oneshot(request_empty(Method::GET, "/parts/connect_info"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "10.0.0.2:4343");
+
+        // same responses as the router returned by `split_for_parts`
+        let service = router().into_make_service().call(()).await.unwrap();
+        let (expected_router, _) = router().split_for_parts();
+        for uri in ["/parts/original_uri?a=b", "/parts/connect_info"] {
+            let response = service.clone().oneshot(request_empty(Method::GET, uri)).await.unwrap();
+            let expected = expected_router
+                .clone()
+                .oneshot(request_empty(Method::GET, uri))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), expected.status());
+            assert_eq!(response_to_str(response).await, response_to_str(expected).await);
+        }
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[allow(deprecated)]
+    async fn parts_host() {
+        let (router, _) = router().split_for_parts();
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/parts/host")
+            .header(HOST, "api.example.com")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "api.example.com");
+
+        assert_traces!("parts_host.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_extension() {
+        let user = CurrentUser {
+            name: "Alice".to_string(),
+        };
+        let (router, _) = router().layer(Extension(user)).split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/parts/extension"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "Alice");
+
+        assert_traces!("parts_extension.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_cookie_jar() {
+        let (router, _) = router().split_for_parts();
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("/parts/cookie_jar")
+            .header(COOKIE, "session=abc123")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "abc123");
+
+        let response = router
+            .oneshot(request_empty(Method::GET, "/parts/cookie_jar"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert_traces!("parts_cookie_jar.traces");
+    }
+
+    fn request_cookie(uri: &str, cookie: &str) -> Request {
+        Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .header(COOKIE, cookie)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    /// Get the `name=value` part of the cookie set by the response.
+    fn response_cookie(response: impl IntoResponse) -> String {
+        let response = response.into_response();
+        let set_cookie = response.headers()[SET_COOKIE].to_str().unwrap();
+        set_cookie.split(';').next().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_signed_cookie_jar() {
+        let key = Key::from(&[7; 64]);
+        let (router, _) = router_cookie_key(key.clone()).split_for_parts();
+
+        let cookie = response_cookie(SignedCookieJar::new(key).add(Cookie::new("session", "abc123")));
+        let response = router
+            .clone()
+            .oneshot(request_cookie("/parts/signed_cookie_jar", &cookie))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "abc123");
+
+        // the signature does not match the value
+        let response = router
+            .oneshot(request_cookie(
+                "/parts/signed_cookie_jar",
+                &cookie.replace("abc123", "abc124"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert_traces!("parts_signed_cookie_jar.traces");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_private_cookie_jar() {
+        let key = Key::generate();
+        let (router, _) = router_cookie_key(key.clone()).split_for_parts();
+
+        let cookie = response_cookie(PrivateCookieJar::new(key).add(Cookie::new("session", "abc123")));
+        assert!(!cookie.contains("abc123"));
+        let response = router
+            .clone()
+            .oneshot(request_cookie("/parts/private_cookie_jar", &cookie))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "abc123");
+
+        // the cookie was not encrypted with the key
+        let response = router
+            .oneshot(request_cookie("/parts/private_cookie_jar", "session=abc123"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // the cookie jar is not traced
+        assert_traces!("parts_private_cookie_jar.traces");
+    }
+
+    #[test]
+    fn parts_cookie_key_openapi() {
+        let (_, doc) = router_cookie_key(Key::generate()).split_for_parts();
+        check_openapi("parts_cookie_key.openapi.json", &doc);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn parts_raw_path_params() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/parts/raw_path_params/user/42"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "kind=user, id=42");
+
+        assert_traces!("parts_raw_path_params.traces");
+    }
+
+    #[test]
+    fn parts_extractors_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("parts_extractors.openapi.json", &doc);
+    }
+}
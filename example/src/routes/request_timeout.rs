@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use axum::BoxError;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::Deserialize;
+use tower::timeout::TimeoutLayer;
+use utoipa::IntoParams;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(sleep))
+        .with_request_timeout(Duration::from_millis(100))
+}
+
+/// Same routes, but the timeout is handled by a fallible `tower::timeout::TimeoutLayer`
+pub fn router_handle_error() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_route(method_router!(sleep))
+        .handle_error(
+            TimeoutLayer::new(Duration::from_millis(100)),
+            |err: BoxError| async move { (StatusCode::SERVICE_UNAVAILABLE, format!("request failed: {err}")) },
+        )
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct SleepParam {
+    /// The duration to wait before responding, in milliseconds
+    ms: u64,
+}
+
+/// This route waits before responding, requests taking more than 100ms time out
+#[autoroute(GET, path="/timeout/sleep/{ms}", tags=["timeout"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The request did not time out"),
+    ]
+)]
+async fn sleep(Path(param): Path<SleepParam>) -> SleepResponses {
+    tokio::time::sleep(Duration::from_millis(param.ms)).await;
+    format!("slept {}ms", param.ms).into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use tower::Service;
+
+    use super::{router, router_handle_error};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn request_timeout() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/timeout/sleep/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "slept 1ms");
+
+        let response = service
+            .call(request_empty(Method::GET, "/timeout/sleep/1000"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn request_timeout_handle_error() {
+        let (mut router, _) = router_handle_error().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/timeout/sleep/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "slept 1ms");
+
+        let response = service
+            .call(request_empty(Method::GET, "/timeout/sleep/1000"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response_to_str(response).await, "request failed: request timed out");
+    }
+
+    #[test]
+    fn request_timeout_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("request_timeout.openapi.json", &doc);
+    }
+}
@@ -0,0 +1,67 @@
+use axum::extract::Path;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_custom_status))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct CalmParam {
+    /// The calm level of the client, from 0 to 10
+    level: u8,
+}
+
+/// This route uses non-standard status codes
+#[autoroute(GET, path="/response/custom_status/{level}", tags=["response"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The client is calm enough"),
+        (420, body=String, serializer=NONE, description="Enhance your calm"),
+    ]
+)]
+async fn response_custom_status(Path(param): Path<CalmParam>) -> ResponseCustomStatusResponses {
+    if param.level >= 5 {
+        "OK".to_string().into_ok()
+    } else {
+        format!("calm level {} is too low", param.level).into_status_420()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use tower::Service;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_custom_status() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/custom_status/7"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "OK");
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/custom_status/2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 420);
+        assert_eq!(response_to_str(response).await, "calm level 2 is too low");
+
+        assert_traces!("response_custom_status.traces");
+    }
+
+    #[test]
+    fn response_custom_status_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_custom_status.openapi.json", &doc);
+    }
+}
@@ -0,0 +1,110 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_default))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct StatusParam {
+    /// The status code to return
+    status: u16,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// An unexpected error
+struct ApiError {
+    /// description of the error
+    message: String,
+}
+
+/// This route returns the requested status code
+#[autoroute(GET, path="/response/default/{status}", tags=["response"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The requested status was 200", example=json!("OK")),
+        (DEFAULT, body=ApiError, description="Unexpected error",
+            example=ApiError { message: "requested status 409 Conflict".to_string() }),
+    ]
+)]
+async fn response_default(Path(param): Path<StatusParam>) -> ResponseDefaultResponses {
+    match StatusCode::from_u16(param.status) {
+        Ok(StatusCode::OK) => "OK".to_string().into_ok(),
+        Ok(status) => ApiError {
+            message: format!("requested status {status}"),
+        }
+        .into_default(status),
+        Err(_) => ApiError {
+            message: format!("invalid status {}", param.status),
+        }
+        .into_default(StatusCode::BAD_REQUEST),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use axum::response::IntoResponse;
+    use axum_autoroute::status_trait::IntoDefault;
+    use serde_json::json;
+    use tower::Service;
+
+    use super::{ApiError, ResponseDefaultResponses, router};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_default() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/default/200"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "OK");
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/default/409"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"message": "requested status 409 Conflict"})
+        );
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/default/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response_to_json(response).await, json!({"message": "invalid status 1"}));
+
+        assert_traces!("response_default.traces");
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "status 200 OK returned for the default response does not match its documentation")
+    )]
+    fn response_default_declared_status() {
+        // a status declared by another response is replaced by a 500 (after a debug assertion)
+        let response: ResponseDefaultResponses = ApiError {
+            message: "not an error".to_string(),
+        }
+        .into_default(StatusCode::OK);
+        assert_eq!(response.into_response().status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn response_default_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_default.openapi.json", &doc);
+    }
+}
@@ -26,7 +26,7 @@ async fn response_file_attachment() -> ResponseFileAttachmentResponses {
 /// Returns an inline file using a `RawResponseBody` and headers.
 #[autoroute(GET, path="/response/file/inline", tags=["response"],
     responses=[
-        (200, body=(HeaderMap, RawResponseBody), serializer=NONE, content_type="application/octet-stream", headers=[(CONTENT_TYPE), (CONTENT_ENCODING)], description="Return an inline file"),
+        (200, body=(HeaderMap, RawResponseBody), serializer=NONE, content_type="application/octet-stream", headers=[(CONTENT_TYPE), (CONTENT_ENCODING), (CONTENT_LENGTH, schema=u64, description="Size of the file in bytes")], description="Return an inline file"),
     ]
 )]
 async fn response_file_inline() -> ResponseFileInlineResponses {
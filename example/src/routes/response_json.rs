@@ -1,5 +1,6 @@
 use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
 use serde::Serialize;
+use serde_json::json;
 use utoipa::ToSchema;
 
 pub fn router() -> AutorouteApiRouter {
@@ -46,9 +47,13 @@ struct NestedStruct<T> {
 }
 
 /// This route always return the same json struct
-#[autoroute(GET, path="/response/json", tags=["response"],
+#[autoroute(GET, path="/response/json", tags=["response"], operation_id="getResponseJson",
     responses=[
-        (200, body=MyResponse, description="Always return the same json"),
+        (200, body=MyResponse, description="Always return the same json",
+            examples=[
+                ("no_enum", json!({"id": 1, "str": "first", "structure": {"name": "nested", "value": 2}, "enum_iterator": []})),
+                ("single_enum", json!({"id": 3, "str": "second", "structure": {"name": "nested", "value": 4}, "enum_iterator": ["Variant1"]})),
+            ]),
     ]
 )]
 async fn response_json() -> ResponseJsonResponses {
@@ -0,0 +1,94 @@
+use axum::extract::Query;
+use axum_autoroute::response::{Paginated, PaginationQuery};
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_paginated))
+}
+
+const USER_COUNT: u32 = 45;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+/// A user of the application
+struct User {
+    /// The id of the user
+    id: u32,
+    /// The name of the user
+    name: String,
+}
+
+/// This route returns a page of the users
+#[autoroute(GET, path="/response/paginated/users", tags=["response"],
+    responses=[
+        (OK, body=Paginated<User>, description="The requested page of users"),
+    ]
+)]
+async fn response_paginated(Query(query): Query<PaginationQuery>) -> ResponsePaginatedResponses {
+    let users = (1..=USER_COUNT)
+        .map(|id| User {
+            id,
+            name: format!("user {id}"),
+        })
+        .skip(usize::try_from(query.offset()).unwrap_or(usize::MAX))
+        .take(query.per_page as usize)
+        .collect();
+    Paginated::new(users, &query, USER_COUNT.into()).into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    async fn response_paginated() {
+        let (router, _) = router().split_for_parts();
+
+        // default pagination
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/response/paginated/users"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = response_to_json(response).await;
+        assert_eq!(json["page"], 1);
+        assert_eq!(json["per_page"], 20);
+        assert_eq!(json["total"], 45);
+        assert_eq!(json["data"].as_array().unwrap().len(), 20);
+        assert_eq!(json["data"][0], json!({"id": 1, "name": "user 1"}));
+
+        // last page
+        let response = router
+            .oneshot(request_empty(
+                Method::GET,
+                "/response/paginated/users?page=3&per_page=20",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = response_to_json(response).await;
+        assert_eq!(json["page"], 3);
+        assert_eq!(
+            json["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|user| &user["id"])
+                .collect::<Vec<_>>(),
+            [41, 42, 43, 44, 45]
+        );
+    }
+
+    #[test]
+    fn response_paginated_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_paginated.openapi.json", &doc);
+    }
+}
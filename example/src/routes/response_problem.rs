@@ -0,0 +1,102 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum_autoroute::response::ProblemDetails;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_problem))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct UserParam {
+    /// The id of the user
+    id: u32,
+}
+
+/// This route only knows the user 1, other users are reported as a problem details error
+#[autoroute(GET, path="/response/problem/{id}", tags=["response"],
+    responses=[
+        (OK, body=String, serializer=NONE, description="The user was found"),
+        (NOT_FOUND, body=ProblemDetails, serializer=NONE, content_type="application/problem+json",
+            description="The user does not exist"),
+    ]
+)]
+async fn response_problem(Path(param): Path<UserParam>) -> ResponseProblemResponses {
+    if param.id == 1 {
+        "user 1".to_string().into_ok()
+    } else {
+        let mut problem = ProblemDetails::new(
+            StatusCode::NOT_FOUND,
+            "User not found",
+            format!("the user {} does not exist", param.id),
+        );
+        problem.instance = Some(format!("/response/problem/{}", param.id));
+        problem.into_not_found()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode, header};
+    use axum::response::IntoResponse;
+    use axum_autoroute::response::ProblemDetails;
+    use serde_json::json;
+    use tower::Service;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_problem() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/problem/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "user 1");
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/problem/2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/problem+json");
+        assert_eq!(
+            response_to_json(response).await,
+            json!({
+                "type": "about:blank",
+                "title": "User not found",
+                "status": 404,
+                "detail": "the user 2 does not exist",
+                "instance": "/response/problem/2",
+            })
+        );
+
+        assert_traces!("response_problem.traces");
+    }
+
+    #[tokio::test]
+    async fn problem_details_into_response() {
+        let mut problem = ProblemDetails::new(StatusCode::CONFLICT, "Conflict", "already exists");
+        problem.detail = None;
+        let response = problem.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/problem+json");
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"type": "about:blank", "title": "Conflict", "status": 409})
+        );
+    }
+
+    #[test]
+    fn response_problem_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_problem.openapi.json", &doc);
+    }
+}
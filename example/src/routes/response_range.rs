@@ -0,0 +1,114 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_range))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct StatusParam {
+    /// The status code to return
+    status: u16,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// An error forwarded from an upstream service
+struct UpstreamError {
+    /// description of the error
+    message: String,
+}
+
+/// This route forwards the requested status code, as a proxy would
+#[autoroute(GET, path="/response/range/{status}", tags=["response"],
+    responses=[
+        (2XX, body=String, serializer=NONE, description="The upstream request succeeded"),
+        (4XX, body=UpstreamError, description="The upstream request was invalid"),
+        (5XX, body=UpstreamError, description="The upstream service failed"),
+    ]
+)]
+async fn response_range(Path(param): Path<StatusParam>) -> ResponseRangeResponses {
+    // the informational and redirection statuses are not documented
+    let status = StatusCode::from_u16(param.status)
+        .ok()
+        .filter(|status| !status.is_informational() && !status.is_redirection())
+        .unwrap_or(StatusCode::BAD_GATEWAY);
+    let message = format!("upstream status {status}");
+    if status.is_success() {
+        message.into_status_2xx(status)
+    } else if status.is_client_error() {
+        UpstreamError { message }.into_status_4xx(status)
+    } else {
+        UpstreamError { message }.into_status_5xx(status)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use axum::response::IntoResponse;
+    use axum_autoroute::status_trait::IntoStatus4xx;
+    use serde_json::json;
+    use tower::Service;
+
+    use super::{ResponseRangeResponses, UpstreamError, router};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_range() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/range/202"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(response_to_str(response).await, "upstream status 202 Accepted");
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/range/404"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"message": "upstream status 404 Not Found"})
+        );
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/range/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(
+            response_to_json(response).await,
+            json!({"message": "upstream status 502 Bad Gateway"})
+        );
+
+        assert_traces!("response_range.traces");
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "status 200 OK returned for the 4XX response does not match its documentation")
+    )]
+    fn response_range_out_of_range() {
+        // a status outside of the documented range is replaced by a 500 (after a debug assertion)
+        let response: ResponseRangeResponses = UpstreamError {
+            message: "not an error".to_string(),
+        }
+        .into_status_4xx(StatusCode::OK);
+        assert_eq!(response.into_response().status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn response_range_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_range.openapi.json", &doc);
+    }
+}
@@ -0,0 +1,113 @@
+use axum::extract::Path;
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_result))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+struct DivisionParams {
+    /// The number to divide
+    dividend: i32,
+    /// The number to divide by
+    divisor: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// The result of a division
+struct Quotient {
+    /// the quotient of the division
+    quotient: i32,
+    /// the remainder of the division
+    remainder: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// An error raised by a division
+struct DivisionError {
+    /// description of the error
+    message: String,
+}
+
+fn checked_divisor(divisor: i32) -> Result<i32, DivisionError> {
+    if divisor == 0 {
+        Err(DivisionError {
+            message: "division by zero".to_string(),
+        })
+    } else {
+        Ok(divisor)
+    }
+}
+
+/// This route divides two numbers, returning a `Result` instead of the responses enum
+#[autoroute(GET, path="/response/result/{dividend}/{divisor}", tags=["response"],
+    ok_status=OK,
+    err_status=BAD_REQUEST,
+)]
+async fn response_result(Path(params): Path<DivisionParams>) -> Result<Quotient, DivisionError> {
+    let divisor = checked_divisor(params.divisor)?;
+    Ok(Quotient {
+        quotient: params.dividend / divisor,
+        remainder: params.dividend % divisor,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode};
+    use axum::response::IntoResponse;
+    use serde_json::json;
+    use tower::Service;
+
+    use super::{DivisionError, Quotient, ResponseResultResponses, router};
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_result() {
+        let (mut router, _) = router().split_for_parts();
+        let service = build_service(&mut router).await;
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/result/7/2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_json(response).await, json!({"quotient": 3, "remainder": 1}));
+
+        let response = service
+            .call(request_empty(Method::GET, "/response/result/7/0"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response_to_json(response).await, json!({"message": "division by zero"}));
+
+        assert_traces!("response_result.traces");
+    }
+
+    #[test]
+    fn response_result_from() {
+        let ok: Result<Quotient, DivisionError> = Ok(Quotient {
+            quotient: 1,
+            remainder: 0,
+        });
+        let response = ResponseResultResponses::from(ok);
+        assert!(matches!(response, ResponseResultResponses::Ok(_)));
+        assert_eq!(response.into_response().status(), StatusCode::OK);
+
+        let err: Result<Quotient, DivisionError> = Err(DivisionError {
+            message: "error".to_string(),
+        });
+        let response = ResponseResultResponses::from(err);
+        assert!(matches!(response, ResponseResultResponses::BadRequest(_)));
+        assert_eq!(response.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn response_result_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_result.openapi.json", &doc);
+    }
+}
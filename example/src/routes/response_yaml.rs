@@ -0,0 +1,61 @@
+use axum_autoroute::{AutorouteApiRouter, autoroute, method_router};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new().with_pub_route(method_router!(response_yaml))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// A struct serialized as yaml
+struct MyYamlResponse {
+    /// this is an u32 field
+    id: u32,
+    /// this is a list of strings
+    names: Vec<String>,
+}
+
+/// This route always return the same yaml struct
+#[autoroute(GET, path="/response/yaml", tags=["response"],
+    responses=[
+        (200, body=MyYamlResponse, serializer=YAML, description="Always return the same yaml"),
+    ]
+)]
+async fn response_yaml() -> ResponseYamlResponses {
+    MyYamlResponse {
+        id: 16,
+        names: vec!["first".to_string(), "second".to_string()],
+    }
+    .into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum::http::{Method, StatusCode, header};
+    use tower::ServiceExt;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[tokio::test]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    async fn response_yaml() {
+        let (router, _) = router().split_for_parts();
+        let response = router
+            .oneshot(request_empty(Method::GET, "/response/yaml"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/yaml");
+        assert_eq!(response_to_str(response).await, "id: 16\nnames:\n- first\n- second\n");
+
+        assert_traces!("response_yaml.traces");
+    }
+
+    #[test]
+    fn response_yaml_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("response_yaml.openapi.json", &doc);
+    }
+}
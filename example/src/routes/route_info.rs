@@ -115,6 +115,7 @@ mod test {
         let info1 = route_info!(super::route_1);
         assert_eq!(info1.method(), Method::GET);
         assert_eq!(info1.path(), "/route/1");
+        assert_eq!(info1.tags(), ["info"]);
 
         let info2 = route_info!(route_2);
         assert_eq!(info2.method(), Method::GET);
@@ -124,4 +125,549 @@ mod test {
         assert_eq!(info3.method(), Method::POST);
         assert_eq!(info3.path(), "/route/{p}");
     }
+
+    #[test]
+    fn route_info_eq_hash() {
+        use std::collections::HashSet;
+
+        use axum_autoroute::RouteInfo;
+
+        let info2 = route_info!(super::route_2);
+        let same_as_info2 = RouteInfo::new(Method::GET, "/route/{p}");
+        assert!(info2 == same_as_info2);
+        assert!(info2 != route_info!(super::route_3)); // different method
+        assert!(info2 != route_info!(super::route_1)); // different path
+
+        let set: HashSet<RouteInfo> = [
+            route_info!(super::route_1),
+            route_info!(super::route_2),
+            route_info!(super::route_3),
+            same_as_info2,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&RouteInfo::new(Method::POST, "/route/{p}")));
+        assert!(!set.contains(&RouteInfo::new(Method::DELETE, "/route/{p}")));
+    }
+
+    #[test]
+    fn route_info_display() {
+        assert_eq!(route_info!(super::route_1).to_string(), "GET /route/1");
+        assert_eq!(route_info!(super::route_3).to_string(), "POST /route/{p}");
+        assert_eq!(
+            format!("called {}", route_info!(super::route_2)),
+            "called GET /route/{p}"
+        );
+    }
+
+    #[test]
+    fn extract_path_params_multi_segments() {
+        use std::collections::HashMap;
+
+        use axum_autoroute::RouteInfo;
+
+        const ROUTE: RouteInfo = RouteInfo::new(Method::GET, "/users/{user_id}/posts/{post_id}");
+        let params = ROUTE.extract_path_params("/users/123/posts/456").unwrap();
+        assert_eq!(params, HashMap::from([("user_id", "123"), ("post_id", "456")]));
+        assert_eq!(params["user_id"], "123");
+        assert_eq!(params["post_id"], "456");
+
+        let params = ROUTE.extract_path_params("/users/john-doe/posts/first_post").unwrap();
+        assert_eq!(params["user_id"], "john-doe");
+        assert_eq!(params["post_id"], "first_post");
+
+        // values are not percent-decoded
+        let params = ROUTE.extract_path_params("/users/john%20doe/posts/1").unwrap();
+        assert_eq!(params["user_id"], "john%20doe");
+
+        // consecutive parameters
+        let route = RouteInfo::new(Method::GET, "/{a}/{b}/{c}");
+        let params = route.extract_path_params("/1/two/3").unwrap();
+        assert_eq!(params, HashMap::from([("a", "1"), ("b", "two"), ("c", "3")]));
+    }
+
+    #[test]
+    fn extract_path_params_no_param() {
+        use axum_autoroute::RouteInfo;
+
+        let params = route_info!(super::route_1).extract_path_params("/route/1").unwrap();
+        assert!(params.is_empty());
+        let params = RouteInfo::new(Method::GET, "/").extract_path_params("/").unwrap();
+        assert!(params.is_empty());
+
+        assert!(route_info!(super::route_1).extract_path_params("/route/2").is_none());
+        assert!(RouteInfo::new(Method::GET, "/").extract_path_params("/route").is_none());
+    }
+
+    #[test]
+    fn extract_path_params_generated_route_info() {
+        use std::collections::HashMap;
+
+        use super::ROUTE_2_ROUTE_INFO;
+
+        // the parameter names borrow the path of the route constant
+        let params: HashMap<&'static str, &str> = ROUTE_2_ROUTE_INFO.extract_path_params("/route/42").unwrap();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params["p"], "42");
+
+        let params = route_info!(super::route_3).extract_path_params("/route/value").unwrap();
+        assert_eq!(params["p"], "value");
+    }
+
+    #[test]
+    fn extract_path_params_wildcard() {
+        use axum_autoroute::RouteInfo;
+
+        let route = RouteInfo::new(Method::GET, "/files/{id}/{*path}");
+        let params = route.extract_path_params("/files/7/dir/sub/file.txt").unwrap();
+        assert_eq!(params["id"], "7");
+        assert_eq!(params["path"], "dir/sub/file.txt");
+
+        let params = route.extract_path_params("/files/7/file.txt").unwrap();
+        assert_eq!(params["path"], "file.txt");
+
+        // the wildcard must capture at least one character
+        assert!(route.extract_path_params("/files/7/").is_none());
+        assert!(route.extract_path_params("/files/7").is_none());
+    }
+
+    #[test]
+    fn extract_path_params_mismatch() {
+        use axum_autoroute::RouteInfo;
+
+        let route = RouteInfo::new(Method::GET, "/users/{user_id}/posts/{post_id}");
+        // literal segment mismatch
+        assert!(route.extract_path_params("/user/123/posts/456").is_none());
+        assert!(route.extract_path_params("/users/123/comments/456").is_none());
+        // too few segments
+        assert!(route.extract_path_params("/users/123/posts").is_none());
+        assert!(route.extract_path_params("/users/123").is_none());
+        assert!(route.extract_path_params("").is_none());
+        // too many segments
+        assert!(route.extract_path_params("/users/123/posts/456/edit").is_none());
+        assert!(route.extract_path_params("/api/users/123/posts/456").is_none());
+        // trailing slash
+        assert!(route.extract_path_params("/users/123/posts/456/").is_none());
+        // empty parameter
+        assert!(route.extract_path_params("/users//posts/456").is_none());
+        assert!(route.extract_path_params("/users/123/posts/").is_none());
+        // case sensitive literal segments
+        assert!(route.extract_path_params("/Users/123/posts/456").is_none());
+    }
+
+    #[test]
+    fn extract_path_params_nested() {
+        use axum_autoroute::{AutorouteApiRouter, RouteInfo, method_router};
+
+        let nested: AutorouteApiRouter = AutorouteApiRouter::new().nest(
+            "/api",
+            AutorouteApiRouter::new().with_pub_route(method_router!(super::route_2)),
+        );
+        let route = &nested.routes()[0];
+        assert_eq!(route, &RouteInfo::new(Method::GET, "/api/route/{p}"));
+        let params = route.extract_path_params("/api/route/abc").unwrap();
+        assert_eq!(params["p"], "abc");
+        assert!(route.extract_path_params("/route/abc").is_none());
+    }
+
+    #[test]
+    fn route_info_matches() {
+        use axum_autoroute::RouteInfo;
+
+        let info2 = route_info!(super::route_2);
+        assert!(info2.matches(&Method::GET, "/route/42"));
+        assert!(info2.matches(&Method::GET, "/route/test"));
+        assert!(route_info!(super::route_1).matches(&Method::GET, "/route/1"));
+        assert!(route_info!(super::route_3).matches(&Method::POST, "/route/1"));
+
+        // method mismatch
+        assert!(!info2.matches(&Method::POST, "/route/42"));
+        assert!(!info2.matches(&Method::HEAD, "/route/42"));
+        assert!(!route_info!(super::route_3).matches(&Method::GET, "/route/1"));
+
+        // path mismatch
+        assert!(!info2.matches(&Method::GET, "/route"));
+        assert!(!info2.matches(&Method::GET, "/route/42/extra"));
+        assert!(!info2.matches(&Method::GET, "/other/42"));
+        assert!(!route_info!(super::route_1).matches(&Method::GET, "/route/2"));
+
+        // method and path mismatch
+        assert!(!info2.matches(&Method::DELETE, "/other/42"));
+
+        let route = RouteInfo::new(Method::PUT, "/files/{*path}");
+        assert!(route.matches(&Method::PUT, "/files/dir/file.txt"));
+        assert!(!route.matches(&Method::PUT, "/files/"));
+        assert!(!route.matches(&Method::GET, "/files/dir/file.txt"));
+    }
+
+    #[test]
+    fn route_info_lookup() {
+        use axum_autoroute::{RouteInfo, routes_info};
+
+        static ROUTES: [RouteInfo; 3] = routes_info!(super::route_1, super::route_2, super::route_3);
+
+        let info: &'static RouteInfo = RouteInfo::lookup(&ROUTES, &Method::GET, "/route/{p}").unwrap();
+        assert_eq!(*info, route_info!(super::route_2));
+        assert_eq!(info.tags(), ["info"]);
+        assert_eq!(
+            RouteInfo::lookup(&ROUTES, &Method::POST, "/route/{p}"),
+            Some(&route_info!(super::route_3))
+        );
+        assert_eq!(
+            RouteInfo::lookup(&ROUTES, &Method::GET, "/route/1"),
+            Some(&route_info!(super::route_1))
+        );
+
+        // the path is a template, not an actual uri path
+        assert!(RouteInfo::lookup(&ROUTES, &Method::GET, "/route/42").is_none());
+        // method mismatch
+        assert!(RouteInfo::lookup(&ROUTES, &Method::DELETE, "/route/{p}").is_none());
+        assert!(RouteInfo::lookup(&[], &Method::GET, "/route/1").is_none());
+    }
+
+    #[test]
+    fn route_registry_table() {
+        use axum_autoroute::{RouteInfo, route_registry};
+
+        const ROUTES: &[RouteInfo] = route_registry!(super::route_1, super::route_2, super::route_3);
+
+        // every registered route appears in the table, in order
+        assert_eq!(
+            ROUTES,
+            [
+                route_info!(super::route_1),
+                route_info!(super::route_2),
+                route_info!(super::route_3)
+            ]
+        );
+        assert_eq!(ROUTES, router().routes());
+
+        // lookup of the route handling an actual uri path, the first matching route being returned
+        assert_eq!(
+            RouteInfo::lookup_matching(ROUTES, &Method::GET, "/route/1"),
+            Some(&route_info!(super::route_1))
+        );
+        assert_eq!(
+            RouteInfo::lookup_matching(ROUTES, &Method::GET, "/route/42"),
+            Some(&route_info!(super::route_2))
+        );
+        assert_eq!(
+            RouteInfo::lookup_matching(ROUTES, &Method::POST, "/route/42"),
+            Some(&route_info!(super::route_3))
+        );
+        assert!(RouteInfo::lookup_matching(ROUTES, &Method::DELETE, "/route/42").is_none());
+        assert!(RouteInfo::lookup_matching(ROUTES, &Method::GET, "/other/42").is_none());
+    }
+
+    #[tokio::test]
+    async fn router_map_routes() {
+        use axum_autoroute::RouteInfo;
+
+        // prefix addition
+        let prefixed = router().map_routes(|info| {
+            let path = format!("/v2{}", info.path());
+            info.with_path(&path)
+        });
+        assert_eq!(
+            prefixed.routes(),
+            [
+                RouteInfo::new(Method::GET, "/v2/route/1"),
+                RouteInfo::new(Method::GET, "/v2/route/{p}"),
+                RouteInfo::new(Method::POST, "/v2/route/{p}")
+            ]
+        );
+        let (mut router, doc) = prefixed.split_for_parts();
+        assert_eq!(
+            doc.paths.paths.keys().collect::<Vec<_>>(),
+            ["/v2/route/1", "/v2/route/{p}"]
+        );
+        let service = build_service(&mut router).await;
+        let response = service.call(request_empty(Method::GET, "/v2/route/1")).await.unwrap();
+        assert_eq!(response_to_str(response).await, "You called GET:/route/1");
+        let response = service
+            .call(request_empty(Method::POST, "/v2/route/57?q=1"))
+            .await
+            .unwrap();
+        assert_eq!(response_to_str(response).await, "You called POST:/route/{p} with 57");
+        let response = service.call(request_empty(Method::GET, "/route/1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // path replacement
+        let (mut router, doc) = super::router()
+            .map_routes(|info| match info.path() {
+                "/route/{p}" => info.with_path("/item/{p}"),
+                _ => info,
+            })
+            .split_for_parts();
+        assert_eq!(doc.paths.paths.keys().collect::<Vec<_>>(), ["/item/{p}", "/route/1"]);
+        let service = build_service(&mut router).await;
+        let response = service.call(request_empty(Method::GET, "/item/42")).await.unwrap();
+        assert_eq!(response_to_str(response).await, "You called GET:/route/{p} with 42");
+        let response = service.call(request_empty(Method::POST, "/item/42")).await.unwrap();
+        assert_eq!(response_to_str(response).await, "You called POST:/route/{p} with 42");
+        let response = service.call(request_empty(Method::GET, "/route/1")).await.unwrap();
+        assert_eq!(response_to_str(response).await, "You called GET:/route/1");
+
+        // method rewriting
+        let (mut router, doc) = super::router()
+            .map_routes(|info| match *info.method() {
+                Method::POST => info.with_method(Method::PUT),
+                _ => info,
+            })
+            .split_for_parts();
+        let item = &doc.paths.paths["/route/{p}"];
+        assert!(item.put.is_some() && item.post.is_none() && item.get.is_some());
+        let service = build_service(&mut router).await;
+        let response = service.call(request_empty(Method::PUT, "/route/42")).await.unwrap();
+        assert_eq!(response_to_str(response).await, "You called POST:/route/{p} with 42");
+        let response = service.call(request_empty(Method::POST, "/route/42")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the path parameters [\"p\"]")]
+    fn router_map_routes_missing_param() {
+        let _ = router().map_routes(|info| info.with_path("/item"));
+    }
+
+    #[test]
+    fn router_routes() {
+        use axum_autoroute::{AutorouteApiRouter, RouteInfo, method_router, method_routers};
+
+        let router = router();
+        assert_eq!(router.routes().len(), 3);
+        assert!(router.private_routes().is_empty());
+
+        let public = AutorouteApiRouter::new().with_pub_route(method_router!(super::route_1));
+        let private = AutorouteApiRouter::new().with_priv_routes(method_routers!(super::route_2, super::route_3));
+        assert!(private.routes().is_empty());
+        assert_eq!(private.private_routes().len(), 2);
+
+        let merged = public.merge(private);
+        assert_eq!(merged.routes(), [route_info!(super::route_1)]);
+        assert_eq!(merged.private_routes().len(), 2);
+
+        let nested = AutorouteApiRouter::new().nest("/api", merged);
+        assert_eq!(nested.routes(), [RouteInfo::new(Method::GET, "/api/route/1")]);
+        assert_eq!(nested.private_routes().len(), 2);
+        let route_3_nested = RouteInfo::new(Method::POST, "/api/route/{p}");
+        assert!(nested.private_routes().iter().any(|route| *route == route_3_nested));
+
+        let nested_twice = router.nest("/v1", nested);
+        assert_eq!(nested_twice.routes().len(), 4);
+        assert!(
+            nested_twice
+                .routes()
+                .iter()
+                .any(|route| *route == route_info!(super::route_1))
+        );
+        assert!(
+            nested_twice
+                .routes()
+                .iter()
+                .any(|route| *route == RouteInfo::new(Method::GET, "/v1/api/route/1"))
+        );
+        assert_eq!(nested_twice.private_routes().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn router_with_route_layer() {
+        use axum::extract::Request;
+        use axum::http::HeaderValue;
+        use axum::middleware::{Next, map_response};
+        use axum::response::Response;
+        use tower::ServiceExt;
+
+        async fn add_header(mut response: Response) -> Response {
+            response
+                .headers_mut()
+                .insert("x-layered", HeaderValue::from_static("true"));
+            response
+        }
+
+        async fn reject(_request: Request, _next: Next) -> StatusCode {
+            StatusCode::UNAUTHORIZED
+        }
+
+        let router = router().with_route_layer(&route_info!(super::route_2), map_response(add_header));
+        let (router, _) = router.split_for_parts();
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-layered"], "true");
+
+        // the other routes (including the ones with the same path) are unaffected
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::POST, "/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key("x-layered"));
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/route/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key("x-layered"));
+
+        // a route layer is not applied to unmatched requests
+        let response = router.oneshot(request_empty(Method::GET, "/unknown")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(!response.headers().contains_key("x-layered"));
+
+        // the layer can also be any middleware rejecting the requests
+        let (router, _) = super::router()
+            .with_route_layer(&route_info!(super::route_1), axum::middleware::from_fn(reject))
+            .split_for_parts();
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/route/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = router.oneshot(request_empty(Method::GET, "/route/1a")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn router_route_count() {
+        use axum_autoroute::{AutorouteApiRouter, method_routers};
+
+        let router = AutorouteApiRouter::new();
+        assert_eq!(router.pub_route_count(), 0);
+        assert_eq!(router.priv_route_count(), 0);
+
+        let router = router.with_pub_routes(method_routers!(super::route_1, super::route_2));
+        assert_eq!(router.pub_route_count(), 2);
+        assert_eq!(router.priv_route_count(), 0);
+
+        let router = router.with_priv_routes(method_routers!(super::route_3));
+        assert_eq!(router.pub_route_count(), 2);
+        assert_eq!(router.priv_route_count(), 1);
+
+        let merged = router.clone().merge(super::router());
+        assert_eq!(merged.pub_route_count(), 5);
+        assert_eq!(merged.priv_route_count(), 1);
+
+        let nested = merged.nest("/api", router);
+        assert_eq!(nested.pub_route_count(), 7);
+        assert_eq!(nested.priv_route_count(), 2);
+        assert_eq!(nested.pub_route_count(), nested.routes().len());
+        assert_eq!(nested.priv_route_count(), nested.private_routes().len());
+    }
+
+    #[test]
+    fn router_contains_route() {
+        use axum_autoroute::{AutorouteApiRouter, RouteInfo, method_routers};
+
+        let router = AutorouteApiRouter::new()
+            .with_pub_routes(method_routers!(super::route_1))
+            .with_priv_routes(method_routers!(super::route_3));
+        assert!(router.contains_route(&route_info!(super::route_1)));
+        // private routes are also checked
+        assert!(router.contains_route(&route_info!(super::route_3)));
+        assert!(!router.contains_route(&route_info!(super::route_2)));
+        // both the method and the path must match
+        assert!(!router.contains_route(&RouteInfo::new(Method::POST, "/route/1")));
+        assert!(!router.contains_route(&RouteInfo::new(Method::GET, "/route/1/")));
+
+        let merged = router.clone().merge(super::router());
+        assert!(merged.contains_route(&route_info!(super::route_2)));
+        let nested = AutorouteApiRouter::new().nest("/api", router);
+        assert!(nested.contains_route(&RouteInfo::new(Method::POST, "/api/route/{p}")));
+        assert!(!nested.contains_route(&route_info!(super::route_1)));
+    }
+
+    #[tokio::test]
+    async fn router_filter_routes() {
+        use tower::ServiceExt;
+
+        let router = router().filter_routes(|info| info.path() == "/route/1");
+        assert_eq!(router.routes(), [route_info!(super::route_1)]);
+
+        let (router, doc) = router.split_for_parts();
+        assert_eq!(doc.paths.paths.len(), 1);
+        assert!(doc.paths.paths["/route/1"].get.is_some());
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/route/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = router.oneshot(request_empty(Method::POST, "/route/57")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn router_filter_routes_same_path() {
+        use tower::ServiceExt;
+
+        let router = router()
+            .with_prefix("/api")
+            .filter_routes(|info| info.method() == Method::POST);
+        assert_eq!(router.routes().len(), 1);
+
+        let (router, doc) = router.split_for_parts();
+        let item = &doc.paths.paths["/api/route/{p}"];
+        assert!(item.post.is_some());
+        assert!(item.get.is_none());
+        assert!(!doc.paths.paths.contains_key("/api/route/1"));
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::POST, "/api/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = router
+            .oneshot(request_empty(Method::GET, "/api/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn router_exclude_paths_from_openapi() {
+        use axum_autoroute::RouteInfo;
+        use tower::ServiceExt;
+
+        let router = router()
+            .with_prefix("/api")
+            .exclude_paths_from_openapi(&["/api/route/{p}"]);
+        assert_eq!(router.routes(), [RouteInfo::new(Method::GET, "/api/route/1")]);
+        assert_eq!(router.private_routes().len(), 2);
+
+        let (_, doc) = router.clone().split_for_parts_with_private_doc();
+        assert!(doc.paths.paths.contains_key("/api/route/{p}"));
+
+        let (router, doc) = router.split_for_parts();
+        assert_eq!(doc.paths.paths.len(), 1);
+        assert!(doc.paths.paths.contains_key("/api/route/1"));
+
+        let response = router
+            .clone()
+            .oneshot(request_empty(Method::GET, "/api/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = router
+            .oneshot(request_empty(Method::POST, "/api/route/57"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
@@ -0,0 +1,113 @@
+use axum_autoroute::{AutorouteApiRouter, SecurityPreset, autoroute, method_routers};
+use utoipa::Modify;
+use utoipa::openapi::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+
+pub fn router() -> AutorouteApiRouter {
+    AutorouteApiRouter::new()
+        .with_pub_routes(method_routers!(security_admin, security_optional, security_presets))
+        .modify_openapi(&SecuritySchemes)
+        .add_security_preset(SecurityPreset::BasicAuth)
+        .add_security_preset(SecurityPreset::ApiKeyQuery)
+}
+
+/// Declares the security schemes used by the routes
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let components = openapi.components.get_or_insert_default();
+        components.add_security_scheme(
+            "BearerAuth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "ApiKey",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+        );
+    }
+}
+
+/// This route requires either a bearer token or an api key (no actual check is performed).
+#[autoroute(GET, path="/security/admin", tags=["security"],
+    security=[("BearerAuth" = []), ("ApiKey" = [])],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Always says welcome"),
+    ]
+)]
+async fn security_admin() -> SecurityAdminResponses {
+    "Welcome admin".to_string().into_ok()
+}
+
+/// This route can be called with or without a bearer token.
+#[autoroute(GET, path="/security/optional", tags=["security"],
+    security=[(), ("BearerAuth" = ["read", "write"])],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Always says welcome"),
+    ]
+)]
+async fn security_optional() -> SecurityOptionalResponses {
+    "Welcome".to_string().into_ok()
+}
+
+/// This route requires either a basic authentication or an api key in the query (no actual check is performed).
+#[autoroute(GET, path="/security/presets", tags=["security"],
+    security=[BasicAuth, ApiKeyQuery = ["read"]],
+    responses=[
+        (OK, body=String, serializer=NONE, description="Always says welcome"),
+    ]
+)]
+async fn security_presets() -> SecurityPresetsResponses {
+    "Welcome".to_string().into_ok()
+}
+
+#[cfg(test)]
+mod test {
+    use axum_autoroute::SecurityPreset;
+
+    use super::router;
+    use crate::test_utils::*;
+
+    #[test]
+    fn security_openapi() {
+        let (_, doc) = router().split_for_parts();
+        check_openapi("security.openapi.json", &doc);
+    }
+
+    #[test]
+    fn security_map_openapi() {
+        let (_, doc) = router()
+            .map_openapi(|doc| doc.info.version = "1.2.3".to_string())
+            .split_for_parts();
+        assert_eq!(doc.info.version, "1.2.3");
+        let schemes = &doc.components.unwrap().security_schemes;
+        assert!(schemes.contains_key("BearerAuth"));
+        assert!(schemes.contains_key("ApiKey"));
+    }
+
+    #[test]
+    fn security_presets() {
+        let (_, doc) = router().split_for_parts();
+        let schemes = &doc.components.unwrap().security_schemes;
+        for preset in [SecurityPreset::BasicAuth, SecurityPreset::ApiKeyQuery] {
+            assert!(schemes[preset.name()] == preset.scheme());
+        }
+
+        let security = doc.paths.paths["/security/presets"]
+            .get
+            .as_ref()
+            .unwrap()
+            .security
+            .clone()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(security).unwrap(),
+            serde_json::json!([{"BasicAuth": []}, {"ApiKeyQuery": ["read"]}])
+        );
+    }
+}
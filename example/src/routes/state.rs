@@ -48,6 +48,23 @@ mod test {
         assert_traces!("state_incr.traces");
     }
 
+    #[tokio::test]
+    async fn state_map_routes() {
+        let router = router()
+            .map_routes(|info| info.with_path("/v2/state/incr"))
+            .with_state::<()>(ApiState::new());
+        let (mut router, _) = router.split_for_parts();
+        let service = build_service(&mut router).await;
+
+        // the state is provided to the handler of the mapped route
+        let response = service
+            .call(request_empty(Method::GET, "/v2/state/incr"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_to_str(response).await, "0");
+    }
+
     #[test]
     fn state_openapi() {
         let (_, doc) = router().split_for_parts();
@@ -134,7 +134,13 @@ fn openapi_ref_dir() -> PathBuf {
 
 #[cfg(feature = "tracing")]
 fn traces_ref_dir() -> PathBuf {
-    current_dir().join("refs").join("tracing")
+    // the structured traces of the `tracing_json` feature have their own reference files
+    let dir = if cfg!(feature = "tracing_json") {
+        "tracing_json"
+    } else {
+        "tracing"
+    };
+    current_dir().join("refs").join(dir)
 }
 
 fn wip_dir() -> PathBuf {
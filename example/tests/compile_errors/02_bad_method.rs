@@ -9,4 +9,16 @@ fn lowercase() {}
 #[autoroute("GET", path="/home")]
 fn lowercase() {}
 
+#[autoroute(methods=[], path="/home")]
+fn no_methods() {}
+
+#[autoroute(methods=[GET, POST, GET], path="/home")]
+fn duplicated_method() {}
+
+#[autoroute(methods=[GET, UNKNOWN], path="/home")]
+fn unknown_method_in_list() {}
+
+#[autoroute(method=[GET], path="/home")]
+fn bad_methods_key() {}
+
 fn main() {}
\ No newline at end of file
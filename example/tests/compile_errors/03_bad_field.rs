@@ -39,4 +39,22 @@ fn missing_responses_5() {}
 #[autoroute(GET, path="/home", unknown="test")]
 fn unknown_field() {}
 
+#[autoroute(GET, path="/home", responses=[(OK, body=String)], params_description=[("id", "first"), ("id", "second")])]
+fn duplicated_param_description() {}
+
+#[autoroute(GET, path="/home", responses=[(OK, body=String)], params_description=[("id")])]
+fn missing_param_description() {}
+
+#[autoroute(GET, path="/home/{id}", responses=[(OK, body=String)], params_description=[("name", "unknown")])]
+fn unknown_param_description(axum::extract::Path(id): axum::extract::Path<u32>) -> UnknownParamDescriptionResponses { todo!() }
+
+#[autoroute(GET, path="/home/{id}", responses=[(OK, body=String)], params_description=[("id", "not extracted")])]
+fn not_extracted_param_description() -> NotExtractedParamDescriptionResponses { todo!() }
+
+#[autoroute(GET, path="/home", responses=[(OK, body=String)], callbacks=[("onEvent", POST, "{$request.body#/url}")])]
+fn missing_callback_responses() {}
+
+#[autoroute(GET, path="/home", responses=[(OK, body=String)], callbacks=[("onEvent", POST, "/url", responses=[(OK, body=String)]), ("onEvent", PUT, "/url", responses=[(OK, body=String)])])]
+fn duplicated_callback() {}
+
 fn main() {}
\ No newline at end of file
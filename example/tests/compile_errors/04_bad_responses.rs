@@ -15,6 +15,18 @@ fn bad_status_3() {}
 #[autoroute(GET, path="/home", responses=[("200")])]
 fn bad_status_4() {}
 
+#[autoroute(GET, path="/home", responses=[(0, body=String)])]
+fn bad_status_5() {}
+
+#[autoroute(GET, path="/home", responses=[(2, body=String)])]
+fn bad_status_6() {}
+
+#[autoroute(GET, path="/home", responses=[(6XX, body=String)])]
+fn bad_status_7() {}
+
+#[autoroute(GET, path="/home", responses=[(2YY, body=String)])]
+fn bad_status_8() {}
+
 #[autoroute(GET, path="/home", responses=[(200)])]
 fn missing_ty_or_filename_1() {}
 
@@ -47,6 +59,23 @@ fn missing_comma() {}
     (OK, body=usize, description="desc"),
 ])]
 /// doc
-fn duplicated_status() -> DuplicatedStatusResponses { todo!() }
+fn duplicated_status() -> DuplicatedStatusResponses {
+    todo!()
+}
+
+#[autoroute(GET, path="/home", responses=[(600, body=String)])]
+fn bad_status_9() {}
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, examples=[("a", "first"), ("a", "second")])])]
+fn duplicated_example() {}
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, headers=[(RETRY_AFTER, type=u32)])])]
+fn bad_header_field() {}
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, headers=[(RETRY_AFTER, schema=u32, schema=String)])])]
+fn duplicated_header_schema() {}
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, headers=[(RETRY_AFTER, description="a", description="b")])])]
+fn duplicated_header_description() {}
 
-fn main() {}
\ No newline at end of file
+fn main() {}
@@ -6,4 +6,13 @@ fn missing_return() {}
 #[autoroute(GET, path="/home", responses=[(200, body=String, description="response description")])]
 fn bad_return() -> Test {}
 
+#[autoroute(GET, path="/home", enum_name="MyResponses", responses=[(200, body=String, description="response description")])]
+fn default_enum_name_with_enum_name() -> DefaultEnumNameWithEnumNameResponses {}
+
+#[autoroute(GET, path="/home", enum_name="not an ident", responses=[(200, body=String, description="response description")])]
+fn bad_enum_name() -> BadEnumNameResponses {}
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, description="response description")])]
+fn impl_return() -> impl axum::response::IntoResponse {}
+
 fn main() {}
\ No newline at end of file
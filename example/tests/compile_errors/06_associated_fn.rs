@@ -0,0 +1,13 @@
+use axum_autoroute::autoroute;
+
+struct Controller {}
+
+impl Controller {
+    #[autoroute(GET, path="/home", responses=[(200, body=String)])]
+    async fn with_self(&self) -> WithSelfResponses {}
+
+    #[autoroute(GET, path="/home", responses=[(200, body=String)])]
+    async fn with_self_state(state: axum::extract::State<std::sync::Arc<Self>>) -> WithSelfStateResponses {}
+}
+
+fn main() {}
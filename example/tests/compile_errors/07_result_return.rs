@@ -0,0 +1,21 @@
+use axum_autoroute::autoroute;
+
+#[autoroute(GET, path="/home", ok_status=OK)]
+fn missing_err_status() -> Result<String, String> {}
+
+#[autoroute(GET, path="/home", err_status=BAD_REQUEST)]
+fn missing_ok_status() -> Result<String, String> {}
+
+#[autoroute(GET, path="/home", ok_status=OK, err_status=BAD_REQUEST, responses=[(OK, body=String)])]
+fn with_responses() -> Result<String, String> {}
+
+#[autoroute(GET, path="/home", ok_status=OK, err_status=DEFAULT)]
+fn runtime_status() -> Result<String, String> {}
+
+#[autoroute(GET, path="/home", ok_status=OK, err_status=BAD_REQUEST)]
+fn not_a_result() -> String {}
+
+#[autoroute(GET, path="/home", ok_status=OK, err_status=OK)]
+fn same_status() -> Result<String, String> {}
+
+fn main() {}
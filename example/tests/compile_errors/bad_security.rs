@@ -0,0 +1,27 @@
+use axum_autoroute::autoroute;
+
+#[autoroute(GET, path="/home", security, responses=[(200, body=Test, description="desc")])]
+fn bad_security_1() {}
+
+#[autoroute(GET, path="/home", security=["BearerAuth"], responses=[(200, body=Test, description="desc")])]
+fn bad_security_2() {}
+
+#[autoroute(GET, path="/home", security=[(BearerAuth = [])], responses=[(200, body=Test, description="desc")])]
+fn bad_security_3() {}
+
+#[autoroute(GET, path="/home", security=[("BearerAuth")], responses=[(200, body=Test, description="desc")])]
+fn bad_security_4() {}
+
+#[autoroute(GET, path="/home", security=[("BearerAuth" = "scope")], responses=[(200, body=Test, description="desc")])]
+fn bad_security_5() {}
+
+#[autoroute(GET, path="/home", security=[("BearerAuth" = [read])], responses=[(200, body=Test, description="desc")])]
+fn bad_security_6() {}
+
+#[autoroute(GET, path="/home", security=[BearerToken], responses=[(200, body=Test, description="desc")])]
+fn bad_security_7() {}
+
+#[autoroute(GET, path="/home", security=[BearerJwt = "scope"], responses=[(200, body=Test, description="desc")])]
+fn bad_security_8() {}
+
+fn main() {}
@@ -27,4 +27,22 @@ fn parts_and_body_extractor(#[extractor(content_type=APPLICATION_JSON, into_para
 #[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
 fn invalid_content_type(#[extractor(content_type=[], into_params=true)] json: CustomJsonExtractor<MyJsonStruct>) -> ContentTypeOnKnownExtractorResponses {}
 
+#[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn invalid_trace_level(#[extractor(trace_level=verbose)] json: CustomJsonExtractor<MyJsonStruct>) -> InvalidTraceLevelResponses {}
+
+#[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn rejection_body_without_status(#[extractor(rejection_body=String)] json: CustomJsonExtractor<MyJsonStruct>) -> RejectionBodyWithoutStatusResponses {}
+
+#[autoroute(GET, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn examples_on_parts_extractor(#[extractor(examples=[("num", 1)])] query: Query<MyQueryStruct>) -> ExamplesOnPartsExtractorResponses {}
+
+#[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn invalid_schema_type(#[extractor(content_type=TEXT_PLAIN, schema_type="uuid")] id: CustomJsonExtractor<MyJsonStruct>) -> InvalidSchemaTypeResponses {}
+
+#[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn schema_format_without_type(#[extractor(content_type=TEXT_PLAIN, schema_format="uuid")] id: CustomJsonExtractor<MyJsonStruct>) -> SchemaFormatWithoutTypeResponses {}
+
+#[autoroute(POST, path="/test", responses=[(200, body=String, serializer=NONE, description="desc")])]
+fn schema_type_without_variant(#[extractor(schema_type="string")] id: CustomJsonExtractor<MyJsonStruct>) -> SchemaTypeWithoutVariantResponses {}
+
 fn main() {}
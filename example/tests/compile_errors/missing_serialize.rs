@@ -0,0 +1,31 @@
+#![allow(unused_imports)]
+
+use axum::Json;
+use axum_autoroute::autoroute;
+use axum;
+use utoipa;
+
+#[derive(Debug, utoipa::ToSchema)]
+pub struct NotSerializable {
+    value: i32,
+}
+
+/// doc
+#[autoroute(GET, path="/default", responses=[(OK, body=NotSerializable, description="desc")])]
+pub fn default_serializer() -> DefaultSerializerResponses {
+    NotSerializable { value: 12 }.into_ok()
+}
+
+/// doc
+#[autoroute(GET, path="/json", responses=[(OK, body=NotSerializable, serializer=Json, description="desc")])]
+pub fn json_serializer() -> JsonSerializerResponses {
+    NotSerializable { value: 12 }.into_ok()
+}
+
+/// doc
+#[autoroute(GET, path="/none", responses=[(OK, body=String, serializer=NONE, description="desc")])]
+pub fn no_serializer() -> NoSerializerResponses {
+    String::new().into_ok()
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![allow(unused_imports)]
+
+use axum::Json;
+use axum_autoroute::autoroute;
+use axum;
+use utoipa;
+
+#[derive(Debug, serde::Serialize)]
+pub struct NoSchema {
+    value: i32,
+}
+
+/// doc
+#[autoroute(GET, path="/default", responses=[(OK, body=NoSchema, description="desc")])]
+pub fn default_serializer() -> DefaultSerializerResponses {
+    NoSchema { value: 12 }.into_ok()
+}
+
+/// doc
+#[autoroute(GET, path="/json", responses=[(OK, body=NoSchema, serializer=Json, description="desc")])]
+pub fn json_serializer() -> JsonSerializerResponses {
+    NoSchema { value: 12 }.into_ok()
+}
+
+/// doc
+#[autoroute(GET, path="/tuple", responses=[(OK, body=((i32, NoSchema)), description="desc")])]
+pub fn tuple_body() -> TupleBodyResponses {
+    (12, NoSchema { value: 12 }).into_ok()
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use axum_autoroute::module_openapi;
+
+#[module_openapi]
+mod no_tags {}
+
+#[module_openapi(tag=["a"])]
+mod bad_key {}
+
+#[module_openapi(tags=["a"], tags=["b"])]
+mod duplicated_tags {}
+
+#[module_openapi(tags=[27])]
+mod bad_tags {}
+
+#[module_openapi(tags=["a"])]
+mod not_inline;
+
+#[module_openapi(tags=["a"])]
+fn not_a_module() {}
+
+fn main() {}
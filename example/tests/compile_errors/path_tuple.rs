@@ -0,0 +1,9 @@
+use axum_autoroute::autoroute;
+
+#[autoroute(GET, path="/test/{id}/{name}", responses=[(200, body=String, serializer=NONE, description="desc")])]
+async fn too_few_elements(Path(params): Path<(u32,)>) -> TooFewElementsResponses {}
+
+#[autoroute(GET, path="/test/{id}", responses=[(200, body=String, serializer=NONE, description="desc")])]
+async fn too_many_elements(Path(params): Path<(u32, String)>) -> TooManyElementsResponses {}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use axum_autoroute::{autoroute, route_registry, RouteInfo};
+
+#[autoroute(GET, path="/home", responses=[(200, body=String, serializer=NONE)])]
+async fn home() -> HomeResponses {
+    String::new().into_ok()
+}
+
+const ROUTES: &[RouteInfo] = route_registry!(home, home);
+
+fn main() {}
@@ -0,0 +1,63 @@
+//! Helper macro to manually implement utoipa traits on a type.
+
+/// Implement `utoipa::ToSchema` or `utoipa::IntoParams` on a type of the calling crate
+/// from a manually written definition.
+///
+/// This is useful for local types that cannot use the utoipa derive macros,
+/// typically because their `serde` representation differs from their rust definition.
+///
+/// Because of the orphan rules, it cannot be used on the types of external crates (e.g. `uuid::Uuid`):
+/// either wrap them in a local newtype to use this macro on,
+/// or use a `#[schema(value_type = ...)]` attribute on the fields using them.
+///
+/// Two forms are available:
+/// * `impl_openapi_for!(MyType as "MyName", schema = ...)` implements `utoipa::ToSchema` (and `utoipa::PartialSchema`).
+///     * The expression must convert into a `utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>`.
+///     * `as "MyName"` is optional, the name of the type is used by default.
+/// * `impl_openapi_for!(MyType, params = ...)` implements `utoipa::IntoParams`.
+///     * The expression must be an iterable of `utoipa::openapi::path::Parameter`.
+///
+/// ```
+/// use axum_autoroute::impl_openapi_for;
+/// use utoipa::openapi::{ObjectBuilder, Type};
+///
+/// struct Timestamp(u64);
+///
+/// impl_openapi_for!(Timestamp as "Timestamp", schema = ObjectBuilder::new().schema_type(Type::Integer).build());
+/// ```
+#[macro_export]
+macro_rules! impl_openapi_for {
+    ($ty:ty as $name:literal, schema = $schema:expr $(,)?) => {
+        impl $crate::__private::utoipa::PartialSchema for $ty {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema>
+            {
+                ($schema).into()
+            }
+        }
+
+        impl $crate::__private::utoipa::ToSchema for $ty {
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed($name)
+            }
+        }
+    };
+    ($ty:ty, schema = $schema:expr $(,)?) => {
+        impl $crate::__private::utoipa::PartialSchema for $ty {
+            fn schema() -> $crate::__private::utoipa::openapi::RefOr<$crate::__private::utoipa::openapi::schema::Schema>
+            {
+                ($schema).into()
+            }
+        }
+
+        impl $crate::__private::utoipa::ToSchema for $ty {}
+    };
+    ($ty:ty, params = $params:expr $(,)?) => {
+        impl $crate::__private::utoipa::IntoParams for $ty {
+            fn into_params(
+                _parameter_in_provider: impl Fn() -> Option<$crate::__private::utoipa::openapi::path::ParameterIn>,
+            ) -> Vec<$crate::__private::utoipa::openapi::path::Parameter> {
+                ($params).into_iter().collect()
+            }
+        }
+    };
+}
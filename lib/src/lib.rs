@@ -6,16 +6,49 @@
 //! * `debugging`: Enables the [`autoroute_debug`] macro.
 //! * `tracing`: Enables automatic tracing of input/output parameters of the handlers.
 //!    * The parameters will be displayed using their `Debug` implementation.
+//! * `tracing_level_trace`, `tracing_level_debug`, `tracing_level_info`: Enable `tracing` and set the default level of the generated traces (`debug` by default).
+//!    * If several of them are enabled, the most verbose level is used (`trace`, then `debug`, then `info`).
+//! * `tracing_json`: Enables `tracing` and emits the traced parameters as structured fields
+//!   (`extractor`/`value`, `status`, `response` and `duration_ms`) instead of formatting them in the message,
+//!   so that they can be serialized as JSON object fields by the subscriber (e.g. `tracing_subscriber::fmt().json()`).
 //! * `default_serializer_json` (default): If enabled, the default serializer for autoroute responses will be [`Json`](axum::extract::Json).
-//! * `unstable_extractor_attr`: Enables some unstable extractor attribute fields.
+//! * `extractor_attr`: Enables the `into_params` and `content_type` extractor attribute fields.
+//! * `unstable_extractor_attr`: Deprecated alias of `extractor_attr`.
+//! * `yaml`: Enables [`AutorouteApiRouter::openapi_yaml`] and the `YAML` response serializer ([`YamlResponse`](response::YamlResponse)).
+//! * `axum-extra`: Enables the detection of the `axum_extra::extract::JsonLines`, `SignedCookieJar` and `PrivateCookieJar` extractors.
+//! * `tower-http`: Enables [`AutorouteApiRouter::with_request_timeout`].
+//! * `cors`: Enables [`AutorouteApiRouter::with_cors`].
+//! * `metrics`: Enables [`AutorouteApiRouter::with_prometheus_metrics`], recording and serving prometheus metrics of the routes.
+//! * `redoc`, `rapidoc`, `scalar`: Enable respectively [`AutorouteApiRouter::serve_redoc`], [`AutorouteApiRouter::serve_rapidoc`]
+//!   and [`AutorouteApiRouter::serve_scalar`], serving the corresponding openapi UI.
 
 #[cfg(feature = "debugging")]
 pub use axum_autoroute_macros::autoroute_debug;
-pub use axum_autoroute_macros::{autoroute, method_router, method_routers, route_info, routes_info};
-pub use route_info::RouteInfo;
-pub use router::AutorouteApiRouter;
+pub use axum_autoroute_macros::{
+    autoroute, autoroute_test, method_router, method_routers, module_openapi, route_info, route_registry, routes_info,
+};
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsConfig;
+pub use route_info::{RegisteredRoute, RouteInfo};
+pub use router::{AutorouteApiRouter, TagMergeStrategy};
+pub use scope::AutorouteApiScope;
+pub use security::SecurityPreset;
 
+mod impl_openapi;
+#[cfg(feature = "metrics")]
+mod metrics;
 pub mod response;
 mod route_info;
 mod router;
+mod scope;
+mod security;
 pub mod status_trait;
+pub mod testing;
+mod validation;
+
+/// Re-exports used by the code generated by the macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+    pub use utoipa;
+}
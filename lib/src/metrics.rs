@@ -0,0 +1,204 @@
+//! Prometheus metrics of the routes (see `AutorouteApiRouter::with_prometheus_metrics`).
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+/// Configuration of the metrics recorded by `AutorouteApiRouter::with_prometheus_metrics_config`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsConfig {
+    prefix: String,
+    method_label: String,
+    path_label: String,
+    status_label: String,
+    buckets: Vec<f64>,
+}
+
+impl Default for MetricsConfig {
+    /// Metrics prefixed with `http`, labelled with `method`, `path` and `status`,
+    /// and using the default buckets of the prometheus client libraries.
+    fn default() -> Self {
+        Self {
+            prefix: "http".to_owned(),
+            method_label: "method".to_owned(),
+            path_label: "path".to_owned(),
+            status_label: "status".to_owned(),
+            buckets: vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0],
+        }
+    }
+}
+
+impl MetricsConfig {
+    /// Create the default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the prefix of the metric names (e.g. `myapp` for `myapp_requests_total`).
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        prefix.clone_into(&mut self.prefix);
+        self
+    }
+
+    /// Set the names of the labels holding the method, the path and the status code of the requests.
+    #[must_use]
+    pub fn with_label_names(mut self, method: &str, path: &str, status: &str) -> Self {
+        method.clone_into(&mut self.method_label);
+        path.clone_into(&mut self.path_label);
+        status.clone_into(&mut self.status_label);
+        self
+    }
+
+    /// Set the upper bounds (in seconds) of the buckets of the latency histogram.
+    /// The bounds are sorted, and the `+Inf` bucket is always added.
+    #[must_use]
+    pub fn with_buckets(mut self, buckets: &[f64]) -> Self {
+        self.buckets = buckets.to_vec();
+        self.buckets.sort_by(f64::total_cmp);
+        self
+    }
+}
+
+/// The metrics recorded for a method and a path.
+#[derive(Default)]
+struct RouteMetrics {
+    /// Number of requests by status code.
+    statuses: BTreeMap<u16, u64>,
+    /// Number of requests by latency bucket (not cumulated), the last one being `+Inf`.
+    buckets: Vec<u64>,
+    /// Sum of the latencies, in seconds.
+    duration_sum: f64,
+}
+
+/// The metrics shared between the metrics layer and the metrics endpoint.
+#[derive(Clone)]
+pub(crate) struct PrometheusMetrics {
+    config: Arc<MetricsConfig>,
+    routes: Arc<Mutex<BTreeMap<(String, String), RouteMetrics>>>,
+}
+
+impl PrometheusMetrics {
+    pub(crate) fn new(config: MetricsConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            routes: Arc::default(),
+        }
+    }
+
+    fn record(&self, method: String, path: String, status: u16, duration: f64) {
+        let bucket_count = self.config.buckets.len() + 1;
+        let bucket = self
+            .config
+            .buckets
+            .iter()
+            .position(|bound| duration <= *bound)
+            .unwrap_or(bucket_count - 1);
+
+        let mut routes = self.routes.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let metrics = routes.entry((method, path)).or_default();
+        *metrics.statuses.entry(status).or_default() += 1;
+        metrics.buckets.resize(bucket_count, 0);
+        metrics.buckets[bucket] += 1;
+        metrics.duration_sum += duration;
+    }
+
+    /// Render the metrics in the prometheus text exposition format.
+    fn render(&self) -> String {
+        let config = &self.config;
+        let prefix = &config.prefix;
+        let routes = self.routes.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let route_labels = |method: &str, path: &str| {
+            format!(
+                "{}=\"{}\",{}=\"{}\"",
+                config.method_label,
+                escape_label(method),
+                config.path_label,
+                escape_label(path)
+            )
+        };
+
+        let mut out = String::new();
+        for (name, help, errors_only) in [
+            ("requests_total", "Total number of HTTP requests.", false),
+            (
+                "request_errors_total",
+                "Total number of HTTP requests answered with a client or server error status code.",
+                true,
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {prefix}_{name} {help}\n# TYPE {prefix}_{name} counter");
+            for ((method, path), metrics) in routes.iter() {
+                for (status, count) in &metrics.statuses {
+                    if !errors_only || *status >= 400 {
+                        let _ = writeln!(
+                            out,
+                            "{prefix}_{name}{{{},{}=\"{status}\"}} {count}",
+                            route_labels(method, path),
+                            config.status_label
+                        );
+                    }
+                }
+            }
+        }
+
+        let name = format!("{prefix}_request_duration_seconds");
+        let _ = writeln!(
+            out,
+            "# HELP {name} HTTP request latency in seconds.\n# TYPE {name} histogram"
+        );
+        for ((method, path), metrics) in routes.iter() {
+            let labels = route_labels(method, path);
+            let bounds = config
+                .buckets
+                .iter()
+                .map(ToString::to_string)
+                .chain(["+Inf".to_owned()]);
+            let mut cumulated = 0;
+            for (bound, count) in bounds.zip(&metrics.buckets) {
+                cumulated += count;
+                let _ = writeln!(out, "{name}_bucket{{{labels},le=\"{bound}\"}} {cumulated}");
+            }
+            let _ = writeln!(out, "{name}_sum{{{labels}}} {}", metrics.duration_sum);
+            let _ = writeln!(out, "{name}_count{{{labels}}} {cumulated}");
+        }
+        out
+    }
+}
+
+/// Escape a label value as required by the prometheus text exposition format.
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Middleware recording the count and latency of the requests handled by a route.
+/// The requests not matching any route (e.g. handled by a fallback) are not recorded, to bound the number of series.
+pub(crate) async fn track_metrics(State(metrics): State<PrometheusMetrics>, request: Request, next: Next) -> Response {
+    let Some(path) = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+    else {
+        return next.run(request).await;
+    };
+    let method = request.method().to_string();
+    let start = Instant::now();
+    let response = next.run(request).await;
+    metrics.record(method, path, response.status().as_u16(), start.elapsed().as_secs_f64());
+    response
+}
+
+/// Handler of the metrics endpoint.
+pub(crate) async fn serve_metrics(State(metrics): State<PrometheusMetrics>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        metrics.render(),
+    )
+}
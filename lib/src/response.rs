@@ -1,8 +1,10 @@
 //! Utilities to generate `Response` with an associated `OpenApi` documentation.
 
 use axum::body::{Body, HttpBody};
+use axum::http::{StatusCode, header};
 use axum::response::IntoResponse;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(ToSchema)]
 #[schema(value_type = String, format = Binary, content_media_type = "application/octet-stream")]
@@ -44,3 +46,199 @@ impl std::fmt::Debug for RawResponseBody {
         f.write_str(&format!("RawResponseBody({size_str})"))
     }
 }
+
+/// Serialize a response body with a JSON serializer (e.g. `axum::Json`).
+///
+/// Used by the code generated by the [`autoroute`](crate::autoroute) macro, so that a body type not implementing
+/// `serde::Serialize` is reported once on the body type, instead of on the `IntoResponse` implementation of the response.
+#[doc(hidden)]
+pub fn serialize_body<T, S>(body: T, serializer: impl FnOnce(T) -> S) -> axum::response::Response
+where
+    T: Serialize,
+    S: IntoResponse,
+{
+    serializer(body).into_response()
+}
+
+/// Status code of a response only known at runtime (`DEFAULT` or a range, e.g. `4XX`),
+/// replaced by a 500 if it does not match the response documented in the openapi specification.
+///
+/// Used by the code generated by the [`autoroute`](crate::autoroute) macro, the mismatch being a bug of the handler
+/// it is also reported with a debug assertion.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn documented_status(status: StatusCode, is_documented: bool, response: &str) -> StatusCode {
+    debug_assert!(
+        is_documented,
+        "status {status} returned for the {response} response does not match its documentation"
+    );
+    if is_documented {
+        status
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+/// Implemented by the types implementing `utoipa::ToSchema`, `Schema` being the type itself.
+///
+/// Used by the code generated by the [`autoroute`](crate::autoroute) macro: the response bodies are documented through
+/// this projection, so that a body type not implementing `utoipa::ToSchema` is reported on the body type,
+/// instead of only in the code generated by the `utoipa::path` macro.
+#[doc(hidden)]
+pub trait CheckedSchema {
+    type Schema: ?Sized;
+}
+
+impl<T> CheckedSchema for T
+where
+    T: ToSchema + ?Sized,
+{
+    type Schema = T;
+}
+
+#[cfg(feature = "yaml")]
+/// Utility struct serializing its content as a YAML response body with the `application/yaml` content type.
+///
+/// It is used by the `serializer=YAML` response option of the [`autoroute`](crate::autoroute) macro.
+/// If the serialization fails, a `500 Internal Server Error` response is returned instead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct YamlResponse<T>(pub T);
+
+#[cfg(feature = "yaml")]
+impl<T> IntoResponse for YamlResponse<T>
+where
+    T: serde::Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        match serde_yaml::to_string(&self.0) {
+            Ok(yaml) => ([(header::CONTENT_TYPE, "application/yaml")], yaml).into_response(),
+            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        }
+    }
+}
+
+/// Problem details error body, as defined by [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807).
+///
+/// It is returned with the `application/problem+json` content type and the status code of its `status` field.
+/// As the [`autoroute`](crate::autoroute) macro sets the status code of the response,
+/// it should be declared with `serializer=NONE` (e.g. `(BAD_REQUEST, body=ProblemDetails, serializer=NONE, content_type="application/problem+json")`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[schema(description = "Problem details error body, as defined by RFC 7807")]
+pub struct ProblemDetails {
+    /// A URI reference identifying the problem type (`about:blank` by default).
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// A short, human-readable summary of the problem type.
+    pub title: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// A human-readable explanation specific to this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// A URI reference identifying the specific occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+}
+
+impl ProblemDetails {
+    /// Create a new `ProblemDetails` of type `about:blank`, without instance.
+    #[must_use]
+    pub fn new(status: StatusCode, title: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            type_: "about:blank".to_string(),
+            title: title.into(),
+            status: status.as_u16(),
+            detail: Some(detail.into()),
+            instance: None,
+        }
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> axum::response::Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        match serde_json::to_string(&self) {
+            Ok(json) => (status, [(header::CONTENT_TYPE, "application/problem+json")], json).into_response(),
+            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        }
+    }
+}
+
+/// A page of items, wrapped in an envelope holding the pagination information.
+///
+/// It is serialized as JSON, e.g. with `(OK, body=Paginated<User>)`,
+/// and can be built from the [`PaginationQuery`] extracted from the request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(description = "A page of items, with the pagination information")]
+pub struct Paginated<T> {
+    /// The items of the page.
+    pub data: Vec<T>,
+    /// The number of the page, starting at 1.
+    pub page: u32,
+    /// The maximum number of items per page.
+    pub per_page: u32,
+    /// The total number of items, across all pages.
+    pub total: u64,
+}
+
+impl<T> Paginated<T> {
+    /// Create a new page of items, for the page requested by `query`.
+    #[must_use]
+    pub fn new(data: Vec<T>, query: &PaginationQuery, total: u64) -> Self {
+        Self {
+            data,
+            page: query.page,
+            per_page: query.per_page,
+            total,
+        }
+    }
+}
+
+impl<T> IntoResponse for Paginated<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self).into_response()
+    }
+}
+
+/// Query parameters selecting a page of items (e.g. `?page=2&per_page=50`), to be extracted with `Query<PaginationQuery>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PaginationQuery {
+    /// The number of the page, starting at 1.
+    #[serde(default = "PaginationQuery::default_page")]
+    #[param(default = 1, minimum = 1)]
+    pub page: u32,
+    /// The maximum number of items per page.
+    #[serde(default = "PaginationQuery::default_per_page")]
+    #[param(default = 20, minimum = 1)]
+    pub per_page: u32,
+}
+
+impl Default for PaginationQuery {
+    fn default() -> Self {
+        Self {
+            page: Self::default_page(),
+            per_page: Self::default_per_page(),
+        }
+    }
+}
+
+impl PaginationQuery {
+    fn default_page() -> u32 {
+        1
+    }
+
+    fn default_per_page() -> u32 {
+        20
+    }
+
+    /// The number of items preceding the requested page (e.g. to be used with `Iterator::skip` or an SQL `OFFSET`).
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page.saturating_sub(1)) * u64::from(self.per_page)
+    }
+}
@@ -1,17 +1,30 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
 use axum::http::Method;
+use utoipa::openapi::path::{Operation, PathItem, Paths};
 
-/// A structure holding information about a route handler (namely its method and path)
+/// A structure holding information about a route handler (namely its method, path and tags)
 /// A new instance of this struct will be implemented by each [`autoroute`](crate::autoroute) handler.
+#[derive(Debug, Clone)]
 pub struct RouteInfo {
     method: Method,
     path: &'static str,
+    tags: &'static [&'static str],
 }
 
 impl RouteInfo {
-    /// Create a new `RouteInfo`.
+    /// Create a new `RouteInfo` without any tag.
     #[must_use]
     pub const fn new(method: Method, path: &'static str) -> Self {
-        Self { method, path }
+        Self::new_with_tags(method, path, &[])
+    }
+
+    /// Create a new `RouteInfo` with the provided tags.
+    #[must_use]
+    pub const fn new_with_tags(method: Method, path: &'static str, tags: &'static [&'static str]) -> Self {
+        Self { method, path, tags }
     }
 
     /// Get the HTTP method handled.
@@ -25,4 +38,283 @@ impl RouteInfo {
     pub fn path(&self) -> &'static str {
         self.path
     }
+
+    /// Get the tags of the route (as declared in the `autoroute` macro).
+    #[must_use]
+    pub fn tags(&self) -> &'static [&'static str] {
+        self.tags
+    }
+
+    /// Extract the path parameters of an actual uri path matching the path of this route.
+    ///
+    /// For instance, `/users/123/posts/456` matched against `/users/{user_id}/posts/{post_id}`
+    /// yields `{"user_id": "123", "post_id": "456"}`.
+    /// A trailing wildcard parameter (e.g. `/files/{*path}`) captures the whole remaining path.
+    ///
+    /// Returns `None` if the path does not match (different number of segments, literal segment mismatch or empty parameter).
+    /// The values are returned as found in the path, without any percent-decoding.
+    #[must_use]
+    pub fn extract_path_params<'a>(&self, actual_path: &'a str) -> Option<HashMap<&'static str, &'a str>> {
+        Some(match_path(self.path, actual_path)?.into_iter().collect())
+    }
+
+    /// Check whether a request with the given method and uri path would be handled by this route.
+    ///
+    /// The path is matched against the path of the route as done by [`extract_path_params`](Self::extract_path_params).
+    #[must_use]
+    pub fn matches(&self, method: &Method, path: &str) -> bool {
+        *method == self.method && match_path(self.path, path).is_some()
+    }
+
+    /// Find the route handling the given method and path template (e.g. `/users/{id}`) among the provided routes.
+    ///
+    /// The path is compared as is to the path of the routes: use [`matches`](Self::matches)
+    /// to find the route handling an actual uri path (e.g. `/users/123`).
+    /// The routes can for instance be a constant table built with [`route_registry`](crate::route_registry).
+    #[must_use]
+    pub fn lookup<'r>(routes: &'r [Self], method: &Method, path: &str) -> Option<&'r Self> {
+        routes
+            .iter()
+            .find(|route| route.method == *method && route.path == path)
+    }
+
+    /// Find the route which would handle a request with the given method and uri path (e.g. `/users/123`)
+    /// among the provided routes, as done by [`matches`](Self::matches).
+    /// If several routes match, the first one is returned.
+    #[must_use]
+    pub fn lookup_matching<'r>(routes: &'r [Self], method: &Method, path: &str) -> Option<&'r Self> {
+        routes.iter().find(|route| route.matches(method, path))
+    }
+}
+
+/// Match an actual uri path against a route path, returning the name and value of each path parameter.
+fn match_path<'p, 'a>(path: &'p str, actual_path: &'a str) -> Option<Vec<(&'p str, &'a str)>> {
+    let mut params = Vec::new();
+    let mut remaining = Some(actual_path);
+    for segment in path.split('/') {
+        let actual = remaining?;
+        let param = segment.strip_prefix('{').and_then(|param| param.strip_suffix('}'));
+
+        // a wildcard parameter captures the rest of the path
+        if let Some(name) = param.and_then(|param| param.strip_prefix('*')) {
+            if actual.is_empty() {
+                return None;
+            }
+            params.push((name, actual));
+            remaining = None;
+            break;
+        }
+
+        let (actual_segment, next) = match actual.split_once('/') {
+            Some((actual_segment, next)) => (actual_segment, Some(next)),
+            None => (actual, None),
+        };
+        match param {
+            Some(_) if actual_segment.is_empty() => return None,
+            Some(name) => params.push((name, actual_segment)),
+            None if segment != actual_segment => return None,
+            None => {}
+        }
+        remaining = next;
+    }
+    // the actual path must not have more segments than the route path
+    remaining.is_none().then_some(params)
+}
+
+/// Two `RouteInfo` are equal if they handle the same method and path (as a route is uniquely identified by them).
+impl PartialEq for RouteInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method && self.path == other.path
+    }
+}
+
+impl Eq for RouteInfo {}
+
+impl Hash for RouteInfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.method.hash(state);
+        self.path.hash(state);
+    }
+}
+
+/// Display the route as `METHOD /path` (e.g. `GET /my/route/{id}`).
+impl Display for RouteInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+/// A route registered in an [`AutorouteApiRouter`](crate::AutorouteApiRouter), namely its method and its actual path
+/// (e.g. prefixed when nested), as listed by [`AutorouteApiRouter::routes`](crate::AutorouteApiRouter::routes).
+/// It can be compared to the [`RouteInfo`] of the handler, as long as the route was not nested or mapped.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegisteredRoute {
+    method: Method,
+    path: String,
+}
+
+impl RegisteredRoute {
+    /// Build the `RegisteredRoute` of each operation declared in the provided openapi paths.
+    pub(crate) fn from_paths(paths: &Paths) -> Vec<Self> {
+        paths
+            .paths
+            .iter()
+            .flat_map(|(path, item)| {
+                Self::path_item_methods(item).map(|method| Self {
+                    method,
+                    path: path.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Remove the operation corresponding to this route from the openapi paths.
+    pub(crate) fn remove_from_paths(&self, paths: &mut Paths) {
+        let _ = self.take_from_paths(paths);
+    }
+
+    /// Remove the operation corresponding to this route from the openapi paths, returning it.
+    pub(crate) fn take_from_paths(&self, paths: &mut Paths) -> Option<Operation> {
+        let item = paths.paths.get_mut(&self.path)?;
+        let operation = self.path_item_operation(item)?.take();
+        if Self::path_item_methods(item).next().is_none() {
+            paths.paths.remove(&self.path);
+        }
+        operation
+    }
+
+    /// Insert the operation of this route in the openapi paths, replacing any existing one.
+    /// Nothing is inserted if the method cannot be documented in openapi (e.g. `CONNECT`).
+    pub(crate) fn insert_in_paths(&self, paths: &mut Paths, operation: Operation) {
+        let mut item = paths.paths.remove(&self.path).unwrap_or_default();
+        if let Some(item_operation) = self.path_item_operation(&mut item) {
+            *item_operation = Some(operation);
+        }
+        if Self::path_item_methods(&item).next().is_some() {
+            paths.paths.insert(self.path.clone(), item);
+        }
+    }
+
+    /// The operation of the path item corresponding to the method of this route.
+    fn path_item_operation<'i>(&self, item: &'i mut PathItem) -> Option<&'i mut Option<Operation>> {
+        Some(match self.method {
+            Method::GET => &mut item.get,
+            Method::PUT => &mut item.put,
+            Method::POST => &mut item.post,
+            Method::DELETE => &mut item.delete,
+            Method::OPTIONS => &mut item.options,
+            Method::HEAD => &mut item.head,
+            Method::PATCH => &mut item.patch,
+            Method::TRACE => &mut item.trace,
+            _ => return None,
+        })
+    }
+
+    /// Methods for which an operation is declared in the path item.
+    fn path_item_methods(item: &PathItem) -> impl Iterator<Item = Method> {
+        [
+            (Method::GET, item.get.is_some()),
+            (Method::PUT, item.put.is_some()),
+            (Method::POST, item.post.is_some()),
+            (Method::DELETE, item.delete.is_some()),
+            (Method::OPTIONS, item.options.is_some()),
+            (Method::HEAD, item.head.is_some()),
+            (Method::PATCH, item.patch.is_some()),
+            (Method::TRACE, item.trace.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(method, declared)| declared.then_some(method))
+    }
+
+    /// Prefix the path of the route as done by `axum::Router::nest`.
+    #[must_use]
+    pub(crate) fn nested(self, prefix: &str) -> Self {
+        let path = if self.path.is_empty() { "/" } else { &self.path };
+        let path = if prefix.ends_with('/') {
+            format!("{prefix}{}", path.trim_start_matches('/'))
+        } else if path == "/" {
+            prefix.to_owned()
+        } else {
+            format!("{prefix}{path}")
+        };
+        Self { path, ..self }
+    }
+
+    /// Replace the HTTP method handled (e.g. to be used with `AutorouteApiRouter::map_routes`).
+    #[must_use]
+    pub fn with_method(self, method: Method) -> Self {
+        Self { method, ..self }
+    }
+
+    /// Replace the uri path handled (e.g. to be used with `AutorouteApiRouter::map_routes`).
+    #[must_use]
+    pub fn with_path(self, path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+            ..self
+        }
+    }
+
+    /// Get the HTTP method handled.
+    #[must_use]
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Get the uri path handled.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Extract the path parameters of an actual uri path matching the path of this route
+    /// (see [`RouteInfo::extract_path_params`]).
+    #[must_use]
+    pub fn extract_path_params<'r, 'a>(&'r self, actual_path: &'a str) -> Option<HashMap<&'r str, &'a str>> {
+        Some(match_path(&self.path, actual_path)?.into_iter().collect())
+    }
+
+    /// Check whether a request with the given method and uri path would be handled by this route
+    /// (see [`RouteInfo::matches`]).
+    #[must_use]
+    pub fn matches(&self, method: &Method, path: &str) -> bool {
+        *method == self.method && match_path(&self.path, path).is_some()
+    }
+
+    /// Names of the path parameters of the route (e.g. `["id"]` for `/users/{id}`).
+    pub(crate) fn path_param_names(&self) -> Vec<&str> {
+        self.path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(|param| param.trim_start_matches('*'))
+            .collect()
+    }
+}
+
+impl From<&RouteInfo> for RegisteredRoute {
+    fn from(route_info: &RouteInfo) -> Self {
+        Self {
+            method: route_info.method.clone(),
+            path: route_info.path.to_owned(),
+        }
+    }
+}
+
+impl PartialEq<RouteInfo> for RegisteredRoute {
+    fn eq(&self, other: &RouteInfo) -> bool {
+        self.method == other.method && self.path == other.path
+    }
+}
+
+impl PartialEq<RegisteredRoute> for RouteInfo {
+    fn eq(&self, other: &RegisteredRoute) -> bool {
+        other == self
+    }
+}
+
+/// Display the route as `METHOD /path` (e.g. `GET /api/my/route/{id}`).
+impl Display for RegisteredRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
 }
@@ -1,16 +1,45 @@
 //! Custom wrapper of `utoipa_axum::router::OpenApiRouter`.
 
 use std::convert::Infallible;
+#[cfg(feature = "tower-http")]
+use std::time::Duration;
 
 use axum::Router;
-use axum::extract::Request;
+use axum::error_handling::HandleErrorLayer;
+use axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
+use axum::extract::{Request, State};
 use axum::handler::Handler;
+#[cfg(feature = "cors")]
+use axum::http::header;
+use axum::http::{Method, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::Route;
+use axum::routing::{IntoMakeService, MethodFilter, MethodRouter, Route, on};
+use tower::layer::util::Stack;
 use tower::{Layer, Service};
-use utoipa::openapi::OpenApi;
+#[cfg(feature = "cors")]
+use tower_http::cors::CorsLayer;
+#[cfg(feature = "tower-http")]
+use tower_http::timeout::TimeoutLayer;
+use utoipa::ToSchema;
+#[cfg(feature = "cors")]
+use utoipa::openapi::Type;
+#[cfg(feature = "cors")]
+use utoipa::openapi::header::{Header, HeaderBuilder};
+use utoipa::openapi::path::Operation;
+#[cfg(feature = "cors")]
+use utoipa::openapi::schema::ObjectBuilder;
+use utoipa::openapi::security::SecurityScheme;
+use utoipa::openapi::tag::TagBuilder;
+use utoipa::openapi::{
+    Components, ContactBuilder, ContentBuilder, ExternalDocs, LicenseBuilder, OpenApi, Paths, RefOr, Response,
+    ResponseBuilder, Schema, ServerBuilder,
+};
 use utoipa_axum::router::{OpenApiRouter, UtoipaMethodRouter};
 
+#[cfg(feature = "metrics")]
+use crate::metrics::{self, MetricsConfig, PrometheusMetrics};
+use crate::{AutorouteApiScope, RegisteredRoute, RouteInfo, SecurityPreset, validation};
+
 /// A wrapper of `utoipa_axum::router::OpenApiRouter`
 /// allowing to separate public and private (not appearing in the openapi specification) routes.
 /// If unspecified, the state of the router will be the unit type.
@@ -19,8 +48,63 @@ pub struct AutorouteApiRouter<S = ()>
 where
     S: Send + Sync + Clone + 'static, // axum State
 {
+    // the openapi routers hold the documentation and the fallbacks, while the routes are kept in separate entries
+    // (until `split_for_parts`) so that they can still be filtered out
     pub_router: OpenApiRouter<S>,
     priv_router: OpenApiRouter<S>,
+    pub_routes: Vec<RouteEntry<S>>,
+    priv_routes: Vec<RouteEntry<S>>,
+    // responses added to every operation of the documentation (see `add_global_response`)
+    global_responses: Vec<(String, RefOr<Response>)>,
+    // how tags declared with the same name but a different definition are handled by `merge` and `nest`
+    tag_merge_strategy: TagMergeStrategy,
+}
+
+/// How `AutorouteApiRouter::merge` (and `nest`) handle a tag declared in both routers with a different definition
+/// (e.g. two sub-routers built with `new_with_openapi` declaring the same tag with different descriptions).
+/// Tags declared identically in both routers are always kept only once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagMergeStrategy {
+    /// The tag of the merged router replaces the existing one.
+    Overwrite,
+    /// The existing tag is kept, the tag of the merged router is ignored.
+    #[default]
+    First,
+    /// Panic on the conflict.
+    Error,
+}
+
+/// A route registered in an `AutorouteApiRouter`.
+#[derive(Clone)]
+struct RouteEntry<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    /// Information about the operations handled by this route.
+    infos: Vec<RegisteredRoute>,
+    router: Router<S>,
+    /// The method router of the handler, used to serve the route at another method or path (see `map`).
+    handler: MethodRouter<S>,
+    /// The methods handled by `handler`, matching `infos`.
+    handler_methods: Vec<Method>,
+}
+
+impl<S> std::fmt::Debug for AutorouteApiRouter<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let routes = |entries: &[RouteEntry<S>]| {
+            RouteEntry::infos(entries)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        f.debug_struct("AutorouteApiRouter")
+            .field("pub_routes", &routes(&self.pub_routes))
+            .field("priv_routes", &routes(&self.priv_routes))
+            .finish_non_exhaustive()
+    }
 }
 
 impl<S> Default for AutorouteApiRouter<S>
@@ -32,6 +116,26 @@ where
     }
 }
 
+/// Wrap an existing `utoipa_axum::router::OpenApiRouter`, all its routes being considered as public.
+impl<S> From<OpenApiRouter<S>> for AutorouteApiRouter<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    fn from(pub_router: OpenApiRouter<S>) -> Self {
+        Self::from_parts(pub_router, OpenApiRouter::new())
+    }
+}
+
+/// Wrap an existing pair of public and private `utoipa_axum::router::OpenApiRouter` (see `from_parts`).
+impl<S> From<(OpenApiRouter<S>, OpenApiRouter<S>)> for AutorouteApiRouter<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    fn from((pub_router, priv_router): (OpenApiRouter<S>, OpenApiRouter<S>)) -> Self {
+        Self::from_parts(pub_router, priv_router)
+    }
+}
+
 impl<S> AutorouteApiRouter<S>
 where
     S: Send + Sync + Clone + 'static, // axum State
@@ -42,6 +146,10 @@ where
         Self {
             pub_router: OpenApiRouter::with_openapi(OpenApi::default()),
             priv_router: OpenApiRouter::new(),
+            pub_routes: Vec::new(),
+            priv_routes: Vec::new(),
+            global_responses: Vec::new(),
+            tag_merge_strategy: TagMergeStrategy::default(),
         }
     }
 
@@ -51,13 +159,28 @@ where
         Self {
             pub_router: OpenApiRouter::with_openapi(openapi),
             priv_router: OpenApiRouter::new(),
+            pub_routes: Vec::new(),
+            priv_routes: Vec::new(),
+            global_responses: Vec::new(),
+            tag_merge_strategy: TagMergeStrategy::default(),
         }
     }
 
+    /// Start a scope accumulating routes under the given path prefix.
+    /// The scope can then be turned into a router with `AutorouteApiScope::build` (or `into`).
+    ///
+    /// This is equivalent to building a sub-router and nesting it with `nest`.
+    #[must_use]
+    pub fn scope(prefix: &str) -> AutorouteApiScope<S> {
+        AutorouteApiScope::new(prefix)
+    }
+
     /// Add a new public route.
     #[must_use]
     pub fn with_pub_route(mut self, method_router: UtoipaMethodRouter<S>) -> Self {
-        self.pub_router = self.pub_router.routes(method_router);
+        let (entry, doc) = RouteEntry::new(method_router);
+        self.pub_router.get_openapi_mut().merge(doc);
+        self.pub_routes.push(entry);
         self
     }
 
@@ -73,7 +196,9 @@ where
     /// Add a new private route.
     #[must_use]
     pub fn with_priv_route(mut self, method_router: UtoipaMethodRouter<S>) -> Self {
-        self.priv_router = self.priv_router.routes(method_router);
+        let (entry, doc) = RouteEntry::new(method_router);
+        self.priv_router.get_openapi_mut().merge(doc);
+        self.priv_routes.push(entry);
         self
     }
 
@@ -86,33 +211,235 @@ where
         method_routers.into_iter().fold(self, Self::with_priv_route)
     }
 
+    /// Get the public routes registered in this router (including the merged and nested ones).
+    #[must_use]
+    pub fn routes(&self) -> Vec<RegisteredRoute> {
+        RouteEntry::infos(&self.pub_routes)
+    }
+
+    /// Get the private routes registered in this router (including the merged and nested ones).
+    #[must_use]
+    pub fn private_routes(&self) -> Vec<RegisteredRoute> {
+        RouteEntry::infos(&self.priv_routes)
+    }
+
+    /// Get the number of public routes (operations) registered in this router, as listed by `routes`.
+    #[must_use]
+    pub fn pub_route_count(&self) -> usize {
+        RouteEntry::count(&self.pub_routes)
+    }
+
+    /// Get the number of private routes (operations) registered in this router, as listed by `private_routes`.
+    #[must_use]
+    pub fn priv_route_count(&self) -> usize {
+        RouteEntry::count(&self.priv_routes)
+    }
+
+    /// Check whether a route (public or private) with the same method and path is registered in this router
+    /// (including the merged and nested ones).
+    #[must_use]
+    pub fn contains_route(&self, route_info: &RouteInfo) -> bool {
+        self.pub_routes
+            .iter()
+            .chain(&self.priv_routes)
+            .any(|entry| entry.infos.iter().any(|info| info == route_info))
+    }
+
+    /// Remove the routes (public and private) for which the predicate returns `false`,
+    /// both from the router and from the openapi documentation.
+    ///
+    /// A route registered with a method router handling several methods is only kept if the predicate
+    /// returns `true` for all of them.
+    #[must_use]
+    pub fn filter_routes<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RegisteredRoute) -> bool,
+    {
+        RouteEntry::filter(&mut self.pub_routes, self.pub_router.get_openapi_mut(), &f);
+        RouteEntry::filter(&mut self.priv_routes, self.priv_router.get_openapi_mut(), &f);
+        self
+    }
+
+    /// Transform the method and path of every route (public and private), both in the router and in the openapi documentation
+    /// (e.g. `router.map_routes(|info| info.with_path(&format!("/v2{}", info.path())))`).
+    /// The handlers are left untouched: a request to the new route is forwarded to the handler with the original method
+    /// (its uri being the one of the new route).
+    ///
+    /// # Panics
+    /// Panics if a transformed route does not have the same path parameters as the original route,
+    /// or if its method cannot be routed by axum.
+    #[must_use]
+    pub fn map_routes<F>(mut self, f: F) -> Self
+    where
+        F: Fn(RegisteredRoute) -> RegisteredRoute,
+    {
+        RouteEntry::map(&mut self.pub_routes, self.pub_router.get_openapi_mut(), &f);
+        RouteEntry::map(&mut self.priv_routes, self.priv_router.get_openapi_mut(), &f);
+        self
+    }
+
+    /// Hide the public routes whose path starts with one of the given prefixes from the openapi documentation,
+    /// while keeping them routable: they become private routes (as if registered with `with_priv_route`).
+    #[must_use]
+    pub fn exclude_paths_from_openapi(mut self, prefixes: &[&str]) -> Self {
+        let is_excluded = |path: &str| prefixes.iter().any(|prefix| path.starts_with(prefix));
+
+        let (excluded, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pub_routes)
+            .into_iter()
+            .partition(|entry| entry.infos.iter().any(|info| is_excluded(info.path())));
+        self.pub_routes = kept;
+        self.priv_routes.extend(excluded);
+
+        // the documentation is moved to the private router, so that it is still available with `split_for_parts_with_private_doc`
+        let pub_paths = &mut self.pub_router.get_openapi_mut().paths.paths;
+        let excluded_paths = pub_paths
+            .keys()
+            .filter(|path| is_excluded(path))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut excluded_items = Paths::new();
+        for path in excluded_paths {
+            if let Some(item) = pub_paths.remove(&path) {
+                excluded_items.paths.insert(path, item);
+            }
+        }
+        self.priv_router.get_openapi_mut().paths.merge(excluded_items);
+        self
+    }
+
     /// Return an `axum::Router` containing all the routes (public and private).
     /// Also returns an instance of utoipa `OpenApi` that will include the documentation only for public routes.
     pub fn split_for_parts(self) -> (Router<S>, OpenApi) {
-        let (router, doc) = self.pub_router.split_for_parts();
-        let router = router.merge(self.priv_router);
-        (router, doc)
+        let (pub_router, priv_router) = self.into_parts();
+        let (router, doc) = pub_router.split_for_parts();
+        (router.merge(priv_router), doc)
     }
 
     /// Same as `split_for_parts` but also including private routes in the documentation
     pub fn split_for_parts_with_private_doc(self) -> (Router<S>, OpenApi) {
-        let merged_router = self.pub_router.merge(self.priv_router);
-        merged_router.split_for_parts()
+        let (pub_router, priv_router) = self.into_parts();
+        pub_router.merge(priv_router).split_for_parts()
+    }
+
+    /// Same as `split_for_parts` but also declaring the provided url as the first server of the documentation
+    /// (before the servers declared with `add_server`), e.g. to validate the documentation against a running server.
+    pub fn split_for_parts_with_base_url(self, base_url: &str) -> (Router<S>, OpenApi) {
+        let (router, mut doc) = self.split_for_parts();
+        doc.servers
+            .get_or_insert_default()
+            .insert(0, ServerBuilder::new().url(base_url).build());
+        (router, doc)
+    }
+
+    /// Return the underlying public and private `utoipa_axum::router::OpenApiRouter`, containing all their routes.
+    #[must_use]
+    pub fn into_parts(self) -> (OpenApiRouter<S>, OpenApiRouter<S>) {
+        let router = self.with_global_responses_applied();
+        (
+            RouteEntry::merge_into(router.pub_router, router.pub_routes),
+            RouteEntry::merge_into(router.priv_router, router.priv_routes),
+        )
+    }
+
+    /// Build a router from a public and a private `utoipa_axum::router::OpenApiRouter` (see `into_parts`).
+    /// The routes already contained in the provided routers are not listed by `routes`/`private_routes`
+    /// and cannot be removed by `filter_routes`.
+    #[must_use]
+    pub fn from_parts(pub_router: OpenApiRouter<S>, priv_router: OpenApiRouter<S>) -> Self {
+        Self {
+            pub_router,
+            priv_router,
+            pub_routes: Vec::new(),
+            priv_routes: Vec::new(),
+            global_responses: Vec::new(),
+            tag_merge_strategy: TagMergeStrategy::default(),
+        }
     }
 
     /// Pass through for `utoipa_axum::router::OpenApiRouter::nest`
     #[must_use]
     pub fn nest(mut self, path: &str, router: Self) -> Self {
+        let mut router = router.with_global_responses_applied();
+        self.resolve_tag_conflicts(&mut router, true);
         self.pub_router = self.pub_router.nest(path, router.pub_router);
         self.priv_router = self.priv_router.nest(path, router.priv_router);
+        self.pub_routes
+            .extend(router.pub_routes.into_iter().map(|entry| entry.nested(path)));
+        self.priv_routes
+            .extend(router.priv_routes.into_iter().map(|entry| entry.nested(path)));
         self
     }
 
+    /// Prepend the given prefix to the path of every route (public and private) of this router.
+    /// The openapi documentation (except for the paths) is kept as is.
+    #[must_use]
+    pub fn with_prefix(self, prefix: &str) -> Self {
+        let without_paths = |router: &OpenApiRouter<S>| {
+            let mut openapi = router.get_openapi().clone();
+            openapi.paths = Paths::new();
+            OpenApiRouter::with_openapi(openapi)
+        };
+        let prefixed = Self {
+            pub_router: without_paths(&self.pub_router),
+            priv_router: without_paths(&self.priv_router),
+            pub_routes: Vec::new(),
+            priv_routes: Vec::new(),
+            global_responses: self.global_responses.clone(),
+            tag_merge_strategy: self.tag_merge_strategy,
+        };
+        prefixed.nest(prefix, self)
+    }
+
     /// Pass through for `utoipa_axum::router::OpenApiRouter::merge`
     #[must_use]
     pub fn merge(mut self, router: Self) -> Self {
+        let mut router = router.with_global_responses_applied();
+        self.resolve_tag_conflicts(&mut router, true);
         self.pub_router = self.pub_router.merge(router.pub_router);
         self.priv_router = self.priv_router.merge(router.priv_router);
+        self.pub_routes.extend(router.pub_routes);
+        self.priv_routes.extend(router.priv_routes);
+        self
+    }
+
+    /// Merge the routes of `router`, after prepending `prefix` to their paths (see `map_routes`).
+    /// Unlike `nest`, no nested axum router is created: the paths of the routes are rewritten,
+    /// both in the router and in the openapi documentation.
+    ///
+    /// Only the routes registered with `with_pub_route` or `with_priv_route` (possibly through a merge or a nest) are prefixed:
+    /// the routes of the routers provided to `from_parts` and the services mounted with `nest_service` are merged as is.
+    #[must_use]
+    pub fn merge_with_prefix(self, prefix: &str, router: Self) -> Self {
+        self.merge(router.map_routes(|info| info.nested(prefix)))
+    }
+
+    /// Merge the public routes of `router` as private routes of `self`:
+    /// they will be served but will not appear in the openapi documentation.
+    /// The private routes of `router` are ignored.
+    #[must_use]
+    pub fn merge_private(mut self, router: Self) -> Self {
+        let mut router = router.with_global_responses_applied();
+        self.resolve_tag_conflicts(&mut router, false);
+        self.priv_router = self.priv_router.merge(router.pub_router);
+        self.priv_routes.extend(router.pub_routes);
+        self
+    }
+
+    /// Pass through for `utoipa_axum::router::OpenApiRouter::nest_service`.
+    /// The service has no openapi documentation: it is mounted in the public router if `public` is true
+    /// (for instance to serve static files documented manually), in the private router otherwise.
+    #[must_use]
+    pub fn nest_service<T>(mut self, path: &str, service: T, public: bool) -> Self
+    where
+        T: Service<Request, Error = Infallible> + Clone + Send + Sync + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        if public {
+            self.pub_router = self.pub_router.nest_service(path, service);
+        } else {
+            self.priv_router = self.priv_router.nest_service(path, service);
+        }
         self
     }
 
@@ -139,11 +466,60 @@ where
         <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
         <L::Service as Service<Request>>::Future: Send + 'static,
     {
+        for entry in self.pub_routes.iter_mut().chain(&mut self.priv_routes) {
+            entry.router = std::mem::take(&mut entry.router).layer(layer.clone());
+            entry.handler = std::mem::take(&mut entry.handler).layer(layer.clone());
+        }
         self.pub_router = self.pub_router.layer(layer.clone());
         self.priv_router = self.priv_router.layer(layer);
         self
     }
 
+    /// Apply a layer only to the given route (public or private), as done by `axum::Router::route_layer`.
+    /// The other routes of the router are not affected.
+    ///
+    /// As the routes are registered by handler, the layer applies to all the methods of the handler of this route
+    /// (for a handler declared with several methods).
+    /// Nothing is done if no route of the router matches the provided `RouteInfo`.
+    #[must_use]
+    pub fn with_route_layer<L>(mut self, route_info: &RouteInfo, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        for entry in self.pub_routes.iter_mut().chain(&mut self.priv_routes) {
+            if entry.infos.iter().any(|info| info == route_info) {
+                entry.router = std::mem::take(&mut entry.router).route_layer(layer.clone());
+                // the handler is only called for the methods it handles, so `layer` is equivalent to `route_layer`
+                entry.handler = std::mem::take(&mut entry.handler).layer(layer.clone());
+            }
+        }
+        self
+    }
+
+    /// Apply a fallible layer to the routes currently registered, its errors being converted into responses by `f`.
+    ///
+    /// As the services of an `axum::Router` cannot fail, `axum::Router` has no `handle_error` method
+    /// and `layer` only accepts infallible layers.
+    /// This wraps `layer` in an `axum::error_handling::HandleErrorLayer` calling `f` before applying it (as done by `layer`).
+    #[must_use]
+    pub fn handle_error<L, F, Fut, Res>(self, layer: L, f: F) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + Send + 'static,
+        <L::Service as Service<Request>>::Error: Send + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+        F: FnOnce(<L::Service as Service<Request>>::Error) -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = Res> + Send + 'static,
+        Res: IntoResponse + 'static,
+    {
+        self.layer(Stack::new(layer, HandleErrorLayer::new(f)))
+    }
+
     /// Pass through for `utoipa_axum::router::OpenApiRouter::with_state`
     #[must_use]
     pub fn with_state<S2>(self, state: S) -> AutorouteApiRouter<S2>
@@ -152,10 +528,33 @@ where
     {
         AutorouteApiRouter {
             pub_router: self.pub_router.with_state(state.clone()),
-            priv_router: self.priv_router.with_state(state),
+            priv_router: self.priv_router.with_state(state.clone()),
+            pub_routes: RouteEntry::with_state(self.pub_routes, &state),
+            priv_routes: RouteEntry::with_state(self.priv_routes, &state),
+            global_responses: self.global_responses,
+            tag_merge_strategy: self.tag_merge_strategy,
         }
     }
 
+    /// Add the schema of `T` (and the schemas it depends on) to the components of the openapi documentation,
+    /// without associating it to any route.
+    /// This is useful for shared types (e.g. a common error structure) that are referenced by several routes.
+    #[must_use]
+    pub fn with_common_response_schema<T>(mut self) -> Self
+    where
+        T: ToSchema,
+    {
+        let mut schemas = vec![(T::name().into_owned(), T::schema())];
+        T::schemas(&mut schemas);
+        self.pub_router
+            .get_openapi_mut()
+            .components
+            .get_or_insert_with(Components::new)
+            .schemas
+            .extend(schemas);
+        self
+    }
+
     /// Apply the provided modifier to the openapi documentation
     #[must_use]
     pub fn modify_openapi<M>(mut self, modifier: &M) -> Self
@@ -166,4 +565,607 @@ where
         modifier.modify(self.priv_router.get_openapi_mut());
         self
     }
+
+    /// Apply the provided closure to the openapi documentation (of the public and of the private routes).
+    /// This is a lighter alternative to `modify_openapi` for one-off adjustments.
+    #[must_use]
+    pub fn map_openapi<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&mut OpenApi),
+    {
+        f(self.pub_router.get_openapi_mut());
+        f(self.priv_router.get_openapi_mut());
+        self
+    }
+
+    /// Add a server to the openapi documentation.
+    /// Several servers can be declared by chaining calls to this method.
+    #[must_use]
+    pub fn add_server(mut self, url: &str, description: Option<&str>) -> Self {
+        let server = ServerBuilder::new().url(url).description(description).build();
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            openapi.servers.get_or_insert_default().push(server.clone());
+        }
+        self
+    }
+
+    /// Declare a security scheme in the components of the openapi documentation,
+    /// so that it can be referenced by the `security` field of the `autoroute` macro (e.g. `security=[("name" = [])]`).
+    #[must_use]
+    pub fn add_security_scheme(mut self, name: &str, scheme: SecurityScheme) -> Self {
+        self.pub_router
+            .get_openapi_mut()
+            .components
+            .get_or_insert_default()
+            .add_security_scheme(name, scheme.clone());
+        self.priv_router
+            .get_openapi_mut()
+            .components
+            .get_or_insert_default()
+            .add_security_scheme(name, scheme);
+        self
+    }
+
+    /// Declare a pre-configured security scheme (see `add_security_scheme`),
+    /// so that it can be referenced without quotes by the `security` field of the `autoroute` macro (e.g. `security=[BearerJwt]`).
+    #[must_use]
+    pub fn add_security_preset(self, preset: SecurityPreset) -> Self {
+        self.add_security_scheme(preset.name(), preset.scheme())
+    }
+
+    /// Set the base path under which the routes are actually served (e.g. `/api/v2` behind a reverse proxy)
+    /// in the openapi documentation (of the public and of the private routes).
+    /// The base path is appended to the url of every server declared so far (see `add_server`),
+    /// or declared as a relative server url if there is none. The paths of the routes are left unchanged.
+    #[must_use]
+    pub fn with_openapi_base_path(mut self, base: &str) -> Self {
+        let base = base.trim_end_matches('/');
+        if base.is_empty() {
+            return self;
+        }
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            for server in openapi.servers.iter_mut().flatten() {
+                server.url = format!("{}{base}", server.url.trim_end_matches('/'));
+            }
+        }
+        // a documentation without server gets the ones of the public routes (or the base path as a relative url),
+        // so that the paths of the private routes are documented under the same base path
+        let servers = self
+            .pub_router
+            .get_openapi()
+            .servers
+            .clone()
+            .filter(|servers| !servers.is_empty())
+            .unwrap_or_else(|| vec![ServerBuilder::new().url(base).build()]);
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            if openapi.servers.as_ref().is_none_or(Vec::is_empty) {
+                openapi.servers = Some(servers.clone());
+            }
+        }
+        self
+    }
+
+    /// Add a response to every operation (public and private) of the openapi documentation,
+    /// including the ones of the routes added afterwards.
+    /// This is useful to document the responses returned by a middleware (e.g. `401` for an authentication layer)
+    /// rather than by the handlers themselves.
+    ///
+    /// The body is documented with the `application/json` content type,
+    /// and operations already documenting a response for this status code are left unchanged.
+    /// The global responses of a router merged or nested into another one only apply to its own routes.
+    #[must_use]
+    pub fn add_global_response<B>(mut self, status: StatusCode, description: &str, body: B) -> Self
+    where
+        B: Into<RefOr<Schema>>,
+    {
+        let response = ResponseBuilder::new()
+            .description(description)
+            .content("application/json", ContentBuilder::new().schema(Some(body)).build())
+            .build();
+        self.global_responses
+            .push((status.as_str().to_owned(), RefOr::T(response)));
+        self
+    }
+
+    /// Apply a `tower_http::timeout::TimeoutLayer` to the routes currently registered (as done by `layer`),
+    /// responding with a `408 Request Timeout` to the requests not handled within the provided duration.
+    ///
+    /// The `408` response is also added to every operation of the openapi documentation, as done by `add_global_response`.
+    #[cfg(feature = "tower-http")]
+    #[must_use]
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        let response = ResponseBuilder::new().description("Request timed out").build();
+        self.global_responses
+            .push((StatusCode::REQUEST_TIMEOUT.as_str().to_owned(), RefOr::T(response)));
+        self.layer(TimeoutLayer::with_status_code(StatusCode::REQUEST_TIMEOUT, timeout))
+    }
+
+    /// Record prometheus metrics for the routes currently registered (as done by `layer`), using the default `MetricsConfig`,
+    /// and serve them on a private route at the provided path (see `with_prometheus_metrics_config`).
+    #[cfg(feature = "metrics")]
+    #[must_use]
+    pub fn with_prometheus_metrics(self, path: &str) -> Self {
+        self.with_prometheus_metrics_config(path, MetricsConfig::default())
+    }
+
+    /// Record prometheus metrics for the routes currently registered (as done by `layer`),
+    /// and serve them on a private route at the provided path, in the prometheus text exposition format.
+    ///
+    /// The following metrics are recorded, labelled with the method and the path template of the route
+    /// (e.g. `/users/{id}`, the requests not matching any route being ignored):
+    /// * `<prefix>_requests_total`: the number of requests, by status code.
+    /// * `<prefix>_request_errors_total`: the number of requests answered with a `4xx` or `5xx` status code, by status code.
+    /// * `<prefix>_request_duration_seconds`: the histogram of the latency of the requests.
+    #[cfg(feature = "metrics")]
+    #[must_use]
+    pub fn with_prometheus_metrics_config(self, path: &str, config: MetricsConfig) -> Self {
+        let metrics = PrometheusMetrics::new(config);
+        let mut router = self.layer(axum::middleware::from_fn_with_state(
+            metrics.clone(),
+            metrics::track_metrics,
+        ));
+        router.priv_router = router
+            .priv_router
+            .route(path, axum::routing::get(metrics::serve_metrics).with_state(metrics));
+        router
+    }
+
+    /// Apply a `tower_http::cors::CorsLayer` to the routes currently registered (as done by `layer`).
+    ///
+    /// The `Access-Control-Allow-Origin`, `Access-Control-Allow-Methods` and `Access-Control-Allow-Headers` headers
+    /// are also added to every response currently documented in the openapi documentation.
+    /// As the configuration of the layer cannot be retrieved, the allowed origins and methods to document must be provided.
+    #[cfg(feature = "cors")]
+    #[must_use]
+    pub fn with_cors(mut self, cors_layer: CorsLayer, allowed_origins: &[&str], allowed_methods: &[Method]) -> Self {
+        let allowed_methods = allowed_methods.iter().map(Method::as_str).collect::<Vec<_>>();
+        let headers = [
+            (
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                format!(
+                    "The origins allowed to access the resource: {}",
+                    allowed_origins.join(", ")
+                ),
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_METHODS,
+                format!(
+                    "The methods allowed to access the resource: {}",
+                    allowed_methods.join(", ")
+                ),
+            ),
+            (
+                header::ACCESS_CONTROL_ALLOW_HEADERS,
+                "The headers allowed in the requests to the resource".to_owned(),
+            ),
+        ]
+        .map(|(name, description)| {
+            let header = HeaderBuilder::new()
+                .schema(ObjectBuilder::new().schema_type(Type::String))
+                .description(Some(description))
+                .build();
+            (name.as_str().to_owned(), header)
+        });
+        add_headers_to_responses(self.pub_router.get_openapi_mut(), &headers);
+        add_headers_to_responses(self.priv_router.get_openapi_mut(), &headers);
+        self.layer(cors_layer)
+    }
+
+    /// Set the contact information of the openapi documentation (replacing the one possibly declared on the `OpenApi` struct).
+    #[must_use]
+    pub fn set_contact(mut self, name: &str, url: Option<&str>, email: Option<&str>) -> Self {
+        let contact = ContactBuilder::new().name(Some(name)).url(url).email(email).build();
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            openapi.info.contact = Some(contact.clone());
+        }
+        self
+    }
+
+    /// Set the license of the openapi documentation (replacing the one possibly declared on the `OpenApi` struct).
+    #[must_use]
+    pub fn set_license(mut self, name: &str, url: Option<&str>) -> Self {
+        let license = LicenseBuilder::new().name(name).url(url).build();
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            openapi.info.license = Some(license.clone());
+        }
+        self
+    }
+
+    /// Declare a tag, with an optional description and link to an external documentation,
+    /// in the openapi documentation (of the public and of the private routes).
+    /// Several tags can be declared by chaining calls to this method, a tag already declared with the same name is replaced.
+    #[must_use]
+    pub fn define_tag(mut self, name: &str, description: Option<&str>, external_docs: Option<ExternalDocs>) -> Self {
+        let tag = TagBuilder::new()
+            .name(name)
+            .description(description)
+            .external_docs(external_docs)
+            .build();
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            let tags = openapi.tags.get_or_insert_default();
+            if let Some(existing) = tags.iter_mut().find(|existing| existing.name == name) {
+                *existing = tag.clone();
+            } else {
+                tags.push(tag.clone());
+            }
+        }
+        self
+    }
+
+    /// Set how tags declared in both routers with a different definition are handled by `merge` and `nest`
+    /// (see `TagMergeStrategy`, the default being `TagMergeStrategy::First`).
+    #[must_use]
+    pub fn with_tag_merge_strategy(mut self, strategy: TagMergeStrategy) -> Self {
+        self.tag_merge_strategy = strategy;
+        self
+    }
+
+    /// Add the given tags to every route (public and private) currently registered in the openapi documentation.
+    /// The existing tags of the routes are kept, a tag already present on a route is not duplicated.
+    #[must_use]
+    pub fn with_tags(mut self, tags: &[&str]) -> Self {
+        for openapi in [self.pub_router.get_openapi_mut(), self.priv_router.get_openapi_mut()] {
+            for operation in operations_mut(openapi) {
+                let operation_tags = operation.tags.get_or_insert_default();
+                for tag in tags {
+                    if !operation_tags.iter().any(|existing| existing == tag) {
+                        operation_tags.push((*tag).to_owned());
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Check that the openapi documentation of the public routes is internally consistent
+    /// (no dangling `$ref`, no duplicated `operationId`).
+    ///
+    /// # Errors
+    /// Returns a human-readable message for each issue found.
+    pub fn validate_openapi(&self) -> Result<(), Vec<String>> {
+        validation::validate_openapi(&self.public_openapi())
+    }
+
+    /// Panic if an `operationId` is used by several operations of the openapi documentation of the public routes,
+    /// listing the duplicates. Intended to be called in tests (e.g. `app().assert_no_duplicate_operation_ids()`).
+    ///
+    /// # Panics
+    /// Panics if a duplicated `operationId` is found.
+    pub fn assert_no_duplicate_operation_ids(&self) {
+        let mut errors = Vec::new();
+        validation::check_operation_ids(&self.public_openapi(), &mut errors);
+        assert!(errors.is_empty(), "duplicated operationId found:\n{}", errors.join("\n"));
+    }
+
+    /// Serialize the openapi documentation of the public routes to a pretty JSON string.
+    ///
+    /// # Errors
+    /// Fails if the documentation cannot be serialized to JSON.
+    pub fn openapi_json(&self) -> Result<String, serde_json::Error> {
+        self.public_openapi().to_pretty_json()
+    }
+
+    /// Serialize the openapi documentation of the public routes to a YAML string.
+    ///
+    /// # Errors
+    /// Fails if the documentation cannot be serialized to YAML.
+    #[cfg(feature = "yaml")]
+    pub fn openapi_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(&self.public_openapi())
+    }
+
+    /// Build a router serving the [Redoc](https://redocly.com/redoc) UI at `route`,
+    /// and the openapi documentation of the public routes (in JSON) at `openapi_route`.
+    /// The returned router is meant to be merged with the one built by `split_for_parts`.
+    #[cfg(feature = "redoc")]
+    pub fn serve_redoc(&self, route: &str, openapi_route: &str) -> Router<S> {
+        use utoipa_redoc::{Redoc, Servable};
+
+        let openapi = self.public_openapi();
+        Router::from(Redoc::with_url(route.to_owned(), openapi.clone())).merge(serve_openapi(openapi_route, openapi))
+    }
+
+    /// Build a router serving the [RapiDoc](https://rapidocweb.com) UI at `route`,
+    /// and the openapi documentation of the public routes (in JSON) at `openapi_route`.
+    /// The returned router is meant to be merged with the one built by `split_for_parts`.
+    #[cfg(feature = "rapidoc")]
+    pub fn serve_rapidoc(&self, route: &str, openapi_route: &str) -> Router<S> {
+        use utoipa_rapidoc::RapiDoc;
+
+        Router::from(RapiDoc::with_url(
+            route.to_owned(),
+            openapi_route.to_owned(),
+            self.public_openapi(),
+        ))
+    }
+
+    /// Build a router serving the [Scalar](https://scalar.com) UI at `route`,
+    /// and the openapi documentation of the public routes (in JSON) at `openapi_route`.
+    /// The returned router is meant to be merged with the one built by `split_for_parts`.
+    #[cfg(feature = "scalar")]
+    pub fn serve_scalar(&self, route: &str, openapi_route: &str) -> Router<S> {
+        use utoipa_scalar::{Scalar, Servable};
+
+        let openapi = self.public_openapi();
+        Router::from(Scalar::with_url(route.to_owned(), openapi.clone())).merge(serve_openapi(openapi_route, openapi))
+    }
+
+    /// The openapi documentation of the public routes, including the global responses.
+    fn public_openapi(&self) -> OpenApi {
+        let mut openapi = self.pub_router.get_openapi().clone();
+        add_responses_to_operations(&mut openapi, &self.global_responses);
+        openapi
+    }
+
+    /// Resolve the conflicts between the tags of `self` and the tags of `router` (which is about to be merged),
+    /// according to the tag merge strategy of `self`.
+    /// If `public` is false, only the private documentations are considered (see `merge_private`).
+    ///
+    /// # Panics
+    /// Panics on a conflict if the strategy is `TagMergeStrategy::Error`.
+    fn resolve_tag_conflicts(&mut self, router: &mut Self, public: bool) {
+        if public {
+            resolve_tag_conflicts(
+                self.pub_router.get_openapi_mut(),
+                router.pub_router.get_openapi_mut(),
+                self.tag_merge_strategy,
+            );
+        }
+        let other = if public {
+            &mut router.priv_router
+        } else {
+            &mut router.pub_router
+        };
+        resolve_tag_conflicts(
+            self.priv_router.get_openapi_mut(),
+            other.get_openapi_mut(),
+            self.tag_merge_strategy,
+        );
+    }
+
+    /// Add the global responses to the documentation of the routes currently registered.
+    fn with_global_responses_applied(mut self) -> Self {
+        let global_responses = std::mem::take(&mut self.global_responses);
+        add_responses_to_operations(self.pub_router.get_openapi_mut(), &global_responses);
+        add_responses_to_operations(self.priv_router.get_openapi_mut(), &global_responses);
+        self
+    }
+}
+
+impl AutorouteApiRouter {
+    /// Shortcut for `split_for_parts` followed by `axum::Router::into_make_service`,
+    /// for when the openapi documentation is not needed.
+    #[must_use]
+    pub fn into_make_service(self) -> IntoMakeService<Router> {
+        self.split_for_parts().0.into_make_service()
+    }
+
+    /// Shortcut for `split_for_parts` followed by `axum::Router::into_make_service_with_connect_info`,
+    /// for when the openapi documentation is not needed.
+    #[must_use]
+    pub fn into_make_service_with_connect_info<C>(self) -> IntoMakeServiceWithConnectInfo<Router, C> {
+        self.split_for_parts().0.into_make_service_with_connect_info()
+    }
+}
+
+/// Build a router serving the openapi documentation in JSON at the given route.
+#[cfg(any(feature = "redoc", feature = "scalar"))]
+fn serve_openapi<S>(route: &str, openapi: OpenApi) -> Router<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    Router::new().route(route, axum::routing::get(move || async { axum::Json(openapi) }))
+}
+
+/// Remove from `other` the tags also declared in `openapi` (which is about to be merged with `other`),
+/// the tags of `openapi` being replaced by the ones of `other` if they differ and `strategy` is `TagMergeStrategy::Overwrite`.
+/// `utoipa` only deduplicates identical tags when merging, so tags with the same name would otherwise be duplicated.
+///
+/// # Panics
+/// Panics if a tag is declared with different definitions and `strategy` is `TagMergeStrategy::Error`.
+fn resolve_tag_conflicts(openapi: &mut OpenApi, other: &mut OpenApi, strategy: TagMergeStrategy) {
+    let (Some(tags), Some(other_tags)) = (openapi.tags.as_mut(), other.tags.as_mut()) else {
+        return;
+    };
+    other_tags.retain(|other_tag| {
+        let Some(tag) = tags.iter_mut().find(|tag| tag.name == other_tag.name) else {
+            return true;
+        };
+        if tag != other_tag {
+            match strategy {
+                TagMergeStrategy::Overwrite => *tag = other_tag.clone(),
+                TagMergeStrategy::First => {}
+                TagMergeStrategy::Error => panic!("tag `{}` is declared with different definitions", tag.name),
+            }
+        }
+        false
+    });
+}
+
+/// Build a method router handling the methods of `method_filter` by forwarding the requests to `handler`
+/// with the provided method (see `AutorouteApiRouter::map_routes`).
+/// The path parameters extracted by the router are kept in the request, and thus available to `handler`.
+fn forward_route<S>(handler: MethodRouter<S>, method: Method, method_filter: MethodFilter) -> MethodRouter<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    on(method_filter, move |State(state): State<S>, mut request: Request| {
+        let mut handler = handler.clone().with_state::<()>(state);
+        *request.method_mut() = method.clone();
+        async move {
+            match Service::call(&mut handler, request).await {
+                Ok(response) => response,
+                Err(infallible) => match infallible {},
+            }
+        }
+    })
+}
+
+/// Add the responses to every operation of the documentation, unless their status code is already documented.
+fn add_responses_to_operations(openapi: &mut OpenApi, responses: &[(String, RefOr<Response>)]) {
+    for operation in operations_mut(openapi) {
+        for (status, response) in responses {
+            operation
+                .responses
+                .responses
+                .entry(status.clone())
+                .or_insert_with(|| response.clone());
+        }
+    }
+}
+
+/// Add the headers to every response (not declared as a reference) of the documentation.
+#[cfg(feature = "cors")]
+fn add_headers_to_responses(openapi: &mut OpenApi, headers: &[(String, Header)]) {
+    for operation in operations_mut(openapi) {
+        for response in operation.responses.responses.values_mut() {
+            if let RefOr::T(response) = response {
+                response.headers.extend(headers.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Iterate over every operation of the documentation.
+fn operations_mut(openapi: &mut OpenApi) -> impl Iterator<Item = &mut Operation> {
+    openapi.paths.paths.values_mut().flat_map(|item| {
+        [
+            &mut item.get,
+            &mut item.put,
+            &mut item.post,
+            &mut item.delete,
+            &mut item.options,
+            &mut item.head,
+            &mut item.patch,
+            &mut item.trace,
+        ]
+        .into_iter()
+        .flatten()
+    })
+}
+
+impl<S> RouteEntry<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    /// Create a new entry from a method router, also returning the documentation of the route.
+    fn new(method_router: UtoipaMethodRouter<S>) -> (Self, OpenApi) {
+        let infos = RegisteredRoute::from_paths(&method_router.1);
+        let handler = method_router.2.clone();
+        let handler_methods = infos.iter().map(|info| info.method().clone()).collect();
+        let (router, doc) = OpenApiRouter::new().routes(method_router).split_for_parts();
+        let entry = Self {
+            infos,
+            router,
+            handler,
+            handler_methods,
+        };
+        (entry, doc)
+    }
+
+    /// Get the information about all the operations of the entries.
+    fn infos(entries: &[Self]) -> Vec<RegisteredRoute> {
+        entries.iter().flat_map(|entry| entry.infos.iter().cloned()).collect()
+    }
+
+    /// Get the number of operations of the entries.
+    fn count(entries: &[Self]) -> usize {
+        entries.iter().map(|entry| entry.infos.len()).sum()
+    }
+
+    /// Nest the route under the provided path.
+    fn nested(self, path: &str) -> Self {
+        Self {
+            infos: self.infos.into_iter().map(|info| info.nested(path)).collect(),
+            router: Router::new().nest(path, self.router),
+            ..self
+        }
+    }
+
+    /// Provide the state to the routes of the entries.
+    fn with_state<S2>(entries: Vec<Self>, state: &S) -> Vec<RouteEntry<S2>>
+    where
+        S2: Send + Sync + Clone + 'static, // resulting axum State
+    {
+        entries
+            .into_iter()
+            .map(|entry| RouteEntry {
+                infos: entry.infos,
+                router: entry.router.with_state(state.clone()),
+                handler: entry.handler.with_state(state.clone()),
+                handler_methods: entry.handler_methods,
+            })
+            .collect()
+    }
+
+    /// Remove the entries not matching the predicate, as well as their documentation.
+    fn filter<F>(entries: &mut Vec<Self>, doc: &mut OpenApi, f: &F)
+    where
+        F: Fn(&RegisteredRoute) -> bool,
+    {
+        entries.retain(|entry| {
+            let keep = entry.infos.iter().all(f);
+            if !keep {
+                entry
+                    .infos
+                    .iter()
+                    .for_each(|info| info.remove_from_paths(&mut doc.paths));
+            }
+            keep
+        });
+    }
+
+    /// Transform the routes of the entries, as well as their documentation (see `AutorouteApiRouter::map_routes`).
+    fn map<F>(entries: &mut [Self], doc: &mut OpenApi, f: &F)
+    where
+        F: Fn(RegisteredRoute) -> RegisteredRoute,
+    {
+        for entry in entries {
+            let mapped: Vec<_> = entry.infos.iter().map(|info| (info.clone(), f(info.clone()))).collect();
+            if mapped.iter().all(|(info, new_info)| info == new_info) {
+                continue;
+            }
+
+            // all the operations are removed before being inserted back, in case two routes are swapped
+            let operations: Vec<_> = mapped
+                .iter()
+                .map(|(info, _)| info.take_from_paths(&mut doc.paths))
+                .collect();
+            for ((_, new_info), operation) in mapped.iter().zip(operations) {
+                if let Some(operation) = operation {
+                    new_info.insert_in_paths(&mut doc.paths, operation);
+                }
+            }
+
+            let mut router = Router::new();
+            for ((info, new_info), method) in mapped.iter().zip(&entry.handler_methods) {
+                let (mut params, mut new_params) = (info.path_param_names(), new_info.path_param_names());
+                params.sort_unstable();
+                new_params.sort_unstable();
+                assert!(
+                    params == new_params,
+                    "`{new_info}` (mapped from `{info}`) should have the path parameters {params:?}"
+                );
+                let method_filter = MethodFilter::try_from(new_info.method().clone())
+                    .unwrap_or_else(|err| panic!("`{new_info}` (mapped from `{info}`): {err}"));
+                router = router.route(
+                    new_info.path(),
+                    forward_route(entry.handler.clone(), method.clone(), method_filter),
+                );
+            }
+            entry.router = router;
+            entry.infos = mapped.into_iter().map(|(_, new_info)| new_info).collect();
+        }
+    }
+
+    /// Merge the routes of the entries into the provided router.
+    fn merge_into(router: OpenApiRouter<S>, entries: Vec<Self>) -> OpenApiRouter<S> {
+        let (router, doc) = router.split_for_parts();
+        let router = entries
+            .into_iter()
+            .fold(router, |router, entry| router.merge(entry.router));
+        let mut router = OpenApiRouter::from(router);
+        *router.get_openapi_mut() = doc;
+        router
+    }
 }
@@ -0,0 +1,92 @@
+//! Builder of prefixed sub-routers.
+
+use utoipa_axum::router::UtoipaMethodRouter;
+
+use crate::AutorouteApiRouter;
+
+/// A builder accumulating routes that share a common path prefix (see [`AutorouteApiRouter::scope`]).
+///
+/// The routes are registered without the prefix, which is applied by nesting the built router when calling `build`
+/// (or when converting the scope into an `AutorouteApiRouter`).
+#[derive(Debug, Clone)]
+pub struct AutorouteApiScope<S = ()>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    prefix: String,
+    router: AutorouteApiRouter<S>,
+}
+
+impl<S> AutorouteApiScope<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    pub(crate) fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+            router: AutorouteApiRouter::new(),
+        }
+    }
+
+    /// The prefix applied to the routes of this scope.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Add a new public route to the scope.
+    #[must_use]
+    pub fn with_pub_route(mut self, method_router: UtoipaMethodRouter<S>) -> Self {
+        self.router = self.router.with_pub_route(method_router);
+        self
+    }
+
+    /// Add several new public routes to the scope.
+    #[must_use]
+    pub fn with_pub_routes<I>(mut self, method_routers: I) -> Self
+    where
+        I: IntoIterator<Item = UtoipaMethodRouter<S>>,
+    {
+        self.router = self.router.with_pub_routes(method_routers);
+        self
+    }
+
+    /// Add a new private route to the scope.
+    #[must_use]
+    pub fn with_priv_route(mut self, method_router: UtoipaMethodRouter<S>) -> Self {
+        self.router = self.router.with_priv_route(method_router);
+        self
+    }
+
+    /// Add several new private routes to the scope.
+    #[must_use]
+    pub fn with_priv_routes<I>(mut self, method_routers: I) -> Self
+    where
+        I: IntoIterator<Item = UtoipaMethodRouter<S>>,
+    {
+        self.router = self.router.with_priv_routes(method_routers);
+        self
+    }
+
+    /// Merge the routes of `router` into the scope, they will also be prefixed.
+    #[must_use]
+    pub fn merge(mut self, router: AutorouteApiRouter<S>) -> Self {
+        self.router = self.router.merge(router);
+        self
+    }
+
+    /// Build a router containing the routes of the scope nested under its prefix.
+    #[must_use]
+    pub fn build(self) -> AutorouteApiRouter<S> {
+        AutorouteApiRouter::new().nest(&self.prefix, self.router)
+    }
+}
+
+impl<S> From<AutorouteApiScope<S>> for AutorouteApiRouter<S>
+where
+    S: Send + Sync + Clone + 'static, // axum State
+{
+    fn from(scope: AutorouteApiScope<S>) -> Self {
+        scope.build()
+    }
+}
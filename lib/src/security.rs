@@ -0,0 +1,46 @@
+//! Pre-configured openapi security schemes.
+
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+
+/// Pre-configured security schemes, which can be referenced without quotes in the `security` field of the `autoroute` macro
+/// (e.g. `security=[BearerJwt]`) once declared with `AutorouteApiRouter::add_security_preset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityPreset {
+    /// A bearer token in the `Authorization` header, formatted as a JWT.
+    BearerJwt,
+    /// A user and a password in the `Authorization` header (HTTP basic authentication).
+    BasicAuth,
+    /// An api key in the `X-API-Key` header.
+    ApiKeyHeader,
+    /// An api key in the `api_key` query parameter.
+    ApiKeyQuery,
+}
+
+impl SecurityPreset {
+    /// The name of the security scheme in the openapi components, also used to reference it in the `autoroute` macro.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BearerJwt => "BearerJwt",
+            Self::BasicAuth => "BasicAuth",
+            Self::ApiKeyHeader => "ApiKeyHeader",
+            Self::ApiKeyQuery => "ApiKeyQuery",
+        }
+    }
+
+    /// The openapi definition of the security scheme.
+    #[must_use]
+    pub fn scheme(self) -> SecurityScheme {
+        match self {
+            Self::BearerJwt => SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+            Self::BasicAuth => SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Basic).build()),
+            Self::ApiKeyHeader => SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+            Self::ApiKeyQuery => SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::new("api_key"))),
+        }
+    }
+}
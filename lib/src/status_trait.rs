@@ -1,6 +1,8 @@
 //! Collection of traits to return responses from an autoroute function.
 //! These traits will be automatically implemented by the `autoroute` macro based on responses declaration.
-//! A trait exists for each HTTP status code.
+//! A trait exists for each HTTP status code, as well as for the openapi `default` response and status code ranges (e.g. `4XX`).
+
+use axum::http::StatusCode;
 
 macro_rules! declare_status_traits {
     ( $( ($status:ident, $code:literal) ),+  $(,)?) => {
@@ -97,3 +99,40 @@ declare_status_traits!(
     (NOT_EXTENDED, 510),
     (NETWORK_AUTHENTICATION_REQUIRED, 511),
 );
+
+/// Helper trait to return the DEFAULT response (openapi catch-all response) from an autoroute function.
+/// Automatically implemented by the `autoroute` macro.
+pub trait IntoDefault<R>: Sized {
+    /// Convert into the enum variant of the autoroute DEFAULT response, returned with the provided http status.
+    ///
+    /// A status declared by another response of the route (explicitly or through a range) is replaced by a 500
+    /// when building the response (and fails a debug assertion).
+    fn into_default(self, status: StatusCode) -> R;
+}
+
+macro_rules! declare_status_range_traits {
+    ( $( ($trait_name:ident, $fn_name:ident, $range:literal) ),+  $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Helper trait to return a ", $range, " response (openapi status code range) from an autoroute function. ",
+                "Automatically implemented by the `autoroute` macro.",
+            )]
+            pub trait $trait_name<R>: Sized {
+                #[doc = concat!(
+                    "Convert into the enum variant of the autoroute ", $range, " response, returned with the provided http status.\n\n",
+                    "A status outside of the ", $range, " range is replaced by a 500 when building the response ",
+                    "(and fails a debug assertion).",
+                )]
+                fn $fn_name(self, status: StatusCode) -> R;
+            }
+        )+
+    };
+}
+
+declare_status_range_traits!(
+    (IntoStatus1xx, into_status_1xx, "1XX"),
+    (IntoStatus2xx, into_status_2xx, "2XX"),
+    (IntoStatus3xx, into_status_3xx, "3XX"),
+    (IntoStatus4xx, into_status_4xx, "4XX"),
+    (IntoStatus5xx, into_status_5xx, "5XX"),
+);
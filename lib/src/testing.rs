@@ -0,0 +1,44 @@
+//! Helpers used by the code generated by the [`autoroute_test`](crate::autoroute_test) macro.
+
+use axum::body::Body;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{Method, Request};
+use axum::response::Response;
+use tower::Service;
+
+use crate::AutorouteApiRouter;
+
+/// Send a request to the given router and check the status code of its response.
+/// If provided, `body` is sent as a JSON body.
+///
+/// # Panics
+/// Panics if the request cannot be built or if the status code of the response is not `expected_status`.
+pub async fn send_request(
+    router: AutorouteApiRouter,
+    method: Method,
+    path: &str,
+    body: Option<serde_json::Value>,
+    expected_status: u16,
+) -> Response {
+    let (mut router, _) = router.split_for_parts();
+
+    let request = Request::builder().method(&method).uri(path);
+    let request = match body {
+        Some(body) => request
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .expect("failed to build the test request");
+
+    let response = match router.call(request).await {
+        Ok(response) => response,
+        Err(infallible) => match infallible {},
+    };
+    assert_eq!(
+        response.status().as_u16(),
+        expected_status,
+        "unexpected status code for `{method} {path}`"
+    );
+    response
+}
@@ -0,0 +1,80 @@
+//! Consistency checks of an openapi documentation.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use utoipa::openapi::OpenApi;
+
+/// Check that the openapi documentation is internally consistent, returning the list of the issues found:
+/// * every local `$ref` must point to an existing element of the documentation.
+/// * every `operationId` must be unique.
+pub(crate) fn validate_openapi(doc: &OpenApi) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    match serde_json::to_value(doc) {
+        Ok(value) => check_refs(&value, &value, "", &mut errors),
+        Err(e) => errors.push(format!("failed to serialize the openapi documentation: {e}")),
+    }
+    check_operation_ids(doc, &mut errors);
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Recursively check that the local `$ref` found in `value` can be resolved in `root`.
+fn check_refs(root: &Value, value: &Value, location: &str, errors: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_location = format!("{location}/{}", key.replace('~', "~0").replace('/', "~1"));
+                if key == "$ref"
+                    && let Value::String(reference) = child
+                    && let Some(pointer) = reference.strip_prefix('#')
+                    && root.pointer(pointer).is_none()
+                {
+                    errors.push(format!("dangling reference '{reference}' at '{child_location}'"));
+                }
+                check_refs(root, child, &child_location, errors);
+            }
+        }
+        Value::Array(array) => {
+            for (index, child) in array.iter().enumerate() {
+                check_refs(root, child, &format!("{location}/{index}"), errors);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Check that each operation id is used by a single operation.
+pub(crate) fn check_operation_ids(doc: &OpenApi, errors: &mut Vec<String>) {
+    let mut operations: HashMap<&str, Vec<String>> = HashMap::new();
+    for (path, item) in &doc.paths.paths {
+        let item_operations = [
+            ("GET", &item.get),
+            ("PUT", &item.put),
+            ("POST", &item.post),
+            ("DELETE", &item.delete),
+            ("OPTIONS", &item.options),
+            ("HEAD", &item.head),
+            ("PATCH", &item.patch),
+            ("TRACE", &item.trace),
+        ];
+        for (method, operation) in item_operations {
+            if let Some(operation_id) = operation.as_ref().and_then(|op| op.operation_id.as_deref()) {
+                operations
+                    .entry(operation_id)
+                    .or_default()
+                    .push(format!("{method} {path}"));
+            }
+        }
+    }
+
+    let mut duplicates: Vec<_> = operations.into_iter().filter(|(_, routes)| routes.len() > 1).collect();
+    duplicates.sort();
+    for (operation_id, routes) in duplicates {
+        errors.push(format!(
+            "operationId '{operation_id}' is used by several operations: {}",
+            routes.join(", ")
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, LitStr, Path, Token};
+
+use crate::utils::error::syn_bail;
+use crate::utils::http::{HttpMethod, HttpStatusCode};
+use crate::utils::spanned::SpannedValue;
+
+/// Enum listing the different parameters of the `autoroute_test` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum AutorouteTestKey {
+    Handler,
+    Method,
+    Path,
+    ExpectedStatus,
+    Body,
+    Router,
+}
+
+/// Struct holding data extracted from the `autoroute_test` macro arguments.
+pub(crate) struct AutorouteTestMeta {
+    /// The handler whose `RouteInfo` provides the default method and path of the request.
+    pub(crate) handler: Option<Path>,
+    /// The method of the request.
+    pub(crate) method: Option<SpannedValue<HttpMethod>>,
+    /// The path of the request (with the actual values of the path parameters).
+    pub(crate) path: Option<LitStr>,
+    /// The status code expected in the response (`200` by default).
+    pub(crate) expected_status: Option<SpannedValue<HttpStatusCode>>,
+    /// The value sent as a JSON body.
+    pub(crate) body: Option<Expr>,
+    /// The expression building the `AutorouteApiRouter` to test (`router()` by default).
+    pub(crate) router: Option<Expr>,
+}
+
+impl Parse for AutorouteTestMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key_error = |span| {
+            syn::Error::new(
+                span,
+                format!(
+                    "expected one of: {}",
+                    AutorouteTestKey::iter()
+                        .map(|key| key.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        };
+
+        let mut meta = Self {
+            handler: None,
+            method: None,
+            path: None,
+            expected_status: None,
+            body: None,
+            router: None,
+        };
+        let mut defined_keys = Vec::new();
+        let mut is_first = true;
+        while !input.is_empty() {
+            if is_first {
+                is_first = false;
+            } else {
+                // allow trailing comma
+                input.parse::<Token![,]>()?;
+                if input.is_empty() {
+                    break;
+                }
+            }
+
+            let ident: Ident = input.parse().map_err(|e| key_error(e.span()))?;
+            let key = AutorouteTestKey::from_str(&ident.to_string()).map_err(|_| key_error(ident.span()))?;
+            if defined_keys.contains(&key) {
+                syn_bail!(ident.span(), "{} already defined", key.to_string());
+            }
+            defined_keys.push(key);
+            input.parse::<Token![=]>()?;
+            match key {
+                AutorouteTestKey::Handler => meta.handler = Some(input.parse()?),
+                AutorouteTestKey::Method => meta.method = Some(input.parse()?),
+                AutorouteTestKey::Path => meta.path = Some(input.parse()?),
+                AutorouteTestKey::ExpectedStatus => {
+                    let status: SpannedValue<HttpStatusCode> = input.parse()?;
+                    if status.is_runtime() {
+                        syn_bail!(status.span(), "expected_status should be an actual status code");
+                    }
+                    meta.expected_status = Some(status);
+                }
+                AutorouteTestKey::Body => meta.body = Some(input.parse()?),
+                AutorouteTestKey::Router => meta.router = Some(input.parse()?),
+            }
+        }
+
+        if meta.handler.is_none() && (meta.method.is_none() || meta.path.is_none()) {
+            syn_bail!(
+                proc_macro2::Span::call_site(),
+                "either `handler` or both `method` and `path` should be defined"
+            );
+        }
+        Ok(meta)
+    }
+}
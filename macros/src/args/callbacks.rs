@@ -0,0 +1,92 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token, Type, bracketed, parenthesized};
+
+use super::responses::AutorouteResponse;
+use crate::syn_bail;
+use crate::utils::http::HttpMethod;
+use crate::utils::parse_named_ident;
+use crate::utils::spanned::SpannedValue;
+
+/// Struct holding the data for a callback declaration in the `autoroute` macro parameters.
+pub(crate) struct AutorouteCallback {
+    /// The name of the callback (key of the callback in the openapi operation).
+    pub(crate) name: LitStr,
+    /// The HTTP method of the request sent by the callback.
+    pub(crate) method: SpannedValue<HttpMethod>,
+    /// The url of the callback, usually a runtime expression (e.g. `"{$request.body#/callback_url}"`).
+    pub(crate) url: LitStr,
+    /// The optional type of the body sent by the callback.
+    pub(crate) body: Option<Type>,
+    /// The list of responses expected from the callback receiver.
+    pub(crate) responses: Vec<AutorouteResponse>,
+    pub(crate) span: Span,
+}
+
+impl std::fmt::Debug for AutorouteCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutorouteCallback")
+            .field("name", &self.name.value())
+            .field("method", &self.method)
+            .field("url", &self.url.value())
+            .field("body", &self.body.as_ref().map(|body| quote! {#body}.to_string()))
+            .field("responses", &self.responses)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Parse for AutorouteCallback {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let parentheses = parenthesized!(content in input);
+
+        let name: LitStr = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let method = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let url: LitStr = content.parse()?;
+        if content.is_empty() {
+            syn_bail!(
+                url.span(),
+                "the responses of the callback `{}` are required",
+                name.value()
+            );
+        }
+        content.parse::<Token![,]>()?;
+
+        // the body is optional, the responses are required
+        let mut body = None;
+        if parse_named_ident(&content, "body").is_ok() {
+            content.parse::<Token![=]>()?;
+            body = Some(content.parse()?);
+            content.parse::<Token![,]>()?;
+        }
+        let responses_ident = parse_named_ident(&content, "responses")?;
+        content.parse::<Token![=]>()?;
+        let responses_content;
+        bracketed!(responses_content in content);
+        let punctuated = responses_content.parse_terminated(AutorouteResponse::parse, Token![,])?;
+        if punctuated.is_empty() {
+            syn_bail!(responses_ident.span(), "at least one callback response is required");
+        }
+        if let Some(resp) = punctuated
+            .iter()
+            .find(|resp| resp.example.is_some() || !resp.examples.is_empty())
+        {
+            syn_bail!(resp.span, "examples are not supported in callback responses");
+        }
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            name,
+            method,
+            url,
+            body,
+            responses: punctuated.into_iter().collect(),
+            span: parentheses.span.join(),
+        })
+    }
+}
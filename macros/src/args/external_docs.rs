@@ -0,0 +1,63 @@
+use proc_macro2::Span;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token, parenthesized};
+
+/// Struct holding the data for an external documentation declaration in the `autoroute` macro parameters.
+pub(crate) struct AutorouteExternalDocs {
+    /// The url of the external documentation.
+    pub(crate) url: LitStr,
+    /// The optional description of the external documentation.
+    pub(crate) description: Option<LitStr>,
+    pub(crate) span: Span,
+}
+
+impl std::fmt::Debug for AutorouteExternalDocs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutorouteExternalDocs")
+            .field("url", &self.url.value())
+            .field("description", &self.description.as_ref().map(LitStr::value))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Parse for AutorouteExternalDocs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let parentheses = parenthesized!(content in input);
+
+        let url = content.parse()?;
+        let mut description = None;
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+        if !content.is_empty() {
+            description = Some(content.parse()?);
+        }
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            url,
+            description,
+            span: parentheses.span.join(),
+        })
+    }
+}
+
+impl ToTokens for AutorouteExternalDocs {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let url = &self.url;
+        let description = self
+            .description
+            .as_ref()
+            .map_or(quote! {None::<String>}, |desc| quote! {Some(#desc)});
+        tokens.extend(quote_spanned! {self.span=>
+            utoipa::openapi::external_docs::ExternalDocsBuilder::new()
+                .url(#url)
+                .description(#description)
+                .build()
+        });
+    }
+}
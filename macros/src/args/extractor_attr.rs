@@ -1,32 +1,92 @@
 use std::str::FromStr;
 
+use quote::quote;
 use strum::IntoEnumIterator;
 use syn::parse::Parse;
-use syn::{Attribute, FnArg, Ident, LitBool, Meta, MetaList, PatType, Token};
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, FnArg, Ident, LitBool, LitStr, Meta, MetaList, PatType, Token, Type};
 
 use crate::AutorouteInput;
+use crate::args::responses::parse_examples;
+use crate::utils::http::HttpStatusCode;
 use crate::utils::path_as_str;
+use crate::utils::spanned::SpannedValue;
+use crate::utils::trace_level::TraceLevel;
+
+/// The openapi types accepted by the `schema_type` parameter of the extractor attribute.
+#[cfg(feature = "extractor_attr")]
+const SCHEMA_TYPES: [&str; 7] = ["string", "integer", "number", "boolean", "array", "object", "null"];
 
 /// Enum listing the different parameters of the extractor attribute.
 #[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::EnumIter)]
 #[strum(serialize_all = "snake_case")]
 pub(crate) enum ExtractorAttrKey {
-    #[cfg(feature = "unstable_extractor_attr")]
+    #[cfg(feature = "extractor_attr")]
     ContentType,
-    #[cfg(feature = "unstable_extractor_attr")]
+    #[cfg(feature = "extractor_attr")]
     IntoParams,
+    #[cfg(feature = "extractor_attr")]
+    SchemaType,
+    #[cfg(feature = "extractor_attr")]
+    SchemaFormat,
     Trace,
+    TraceLevel,
+    RejectionStatus,
+    RejectionBody,
+    Examples,
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub(crate) struct ExtractorAttr {
     /// Indicates whether the extractor should be logged or not
     pub(crate) do_trace: Option<LitBool>,
-    #[cfg(feature = "unstable_extractor_attr")]
+    /// The level of the extractor trace
+    pub(crate) trace_level: TraceLevel,
+    /// The status code of the response returned when the extraction fails, to add in the openapi documentation.
+    pub(crate) rejection_status: Option<SpannedValue<HttpStatusCode>>,
+    /// The body type of the response returned when the extraction fails (`String` if not specified).
+    pub(crate) rejection_body: Option<Type>,
+    /// A list of named examples of the request body (any expressions implementing `serde::Serialize`).
+    pub(crate) examples: Vec<(LitStr, Expr)>,
+    #[cfg(feature = "extractor_attr")]
     pub(crate) variant: ExtractorAttrVariant,
+    /// The openapi schema type overriding the one of the extracted type (e.g. `"string"`).
+    #[cfg(feature = "extractor_attr")]
+    pub(crate) schema_type: Option<LitStr>,
+    /// The openapi schema format overriding the one of the extracted type (e.g. `"uuid"`).
+    #[cfg(feature = "extractor_attr")]
+    pub(crate) schema_format: Option<LitStr>,
+}
+
+impl std::fmt::Debug for ExtractorAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ExtractorAttr");
+        debug
+            .field("do_trace", &self.do_trace.as_ref().map(|do_trace| do_trace.value))
+            .field("trace_level", &self.trace_level)
+            .field("rejection_status", &self.rejection_status)
+            .field(
+                "rejection_body",
+                &self.rejection_body.as_ref().map(|body| quote! {#body}.to_string()),
+            )
+            .field(
+                "examples",
+                &self
+                    .examples
+                    .iter()
+                    .map(|(name, value)| (name.value(), quote! {#value}.to_string()))
+                    .collect::<Vec<_>>(),
+            );
+        #[cfg(feature = "extractor_attr")]
+        debug
+            .field("variant", &self.variant)
+            .field("schema_type", &self.schema_type.as_ref().map(LitStr::value))
+            .field("schema_format", &self.schema_format.as_ref().map(LitStr::value));
+        debug.finish()
+    }
 }
 
-#[cfg(feature = "unstable_extractor_attr")]
+#[cfg(feature = "extractor_attr")]
 #[derive(Debug, Default)]
 pub(crate) enum ExtractorAttrVariant {
     /// Nothing was specified about this extractor in the attributes.
@@ -81,47 +141,131 @@ impl Parse for ExtractorAttr {
                 ExtractorAttrKey::Trace => {
                     extractor_attr.do_trace = Some(input.parse()?);
                 }
-                #[cfg(feature = "unstable_extractor_attr")]
+                ExtractorAttrKey::TraceLevel => {
+                    extractor_attr.trace_level = input.parse()?;
+                }
+                ExtractorAttrKey::RejectionStatus => {
+                    extractor_attr.rejection_status = Some(Self::parse_rejection_status(input)?);
+                }
+                ExtractorAttrKey::RejectionBody => {
+                    extractor_attr.rejection_body = Some(input.parse()?);
+                }
+                ExtractorAttrKey::Examples => {
+                    extractor_attr.examples = parse_examples(input)?;
+                }
+                #[cfg(feature = "extractor_attr")]
                 ExtractorAttrKey::IntoParams => {
-                    let value = input.parse()?;
-                    match &mut extractor_attr.variant {
-                        ExtractorAttrVariant::Unspecified => {
-                            extractor_attr.variant = ExtractorAttrVariant::PartsExtractor { into_params: value }
-                        }
-                        ExtractorAttrVariant::PartsExtractor { into_params } => *into_params = value,
-                        ExtractorAttrVariant::BodyExtractor { content_types: _ } => crate::syn_bail!(
-                            ident.span(),
-                            "into_params cannot be defined in an extractor attribute containing content_type"
-                        ),
-                    }
+                    extractor_attr.parse_into_params(input, &ident)?;
+                }
+                #[cfg(feature = "extractor_attr")]
+                ExtractorAttrKey::SchemaType => {
+                    extractor_attr.schema_type = Some(Self::parse_schema_type(input)?);
+                }
+                #[cfg(feature = "extractor_attr")]
+                ExtractorAttrKey::SchemaFormat => {
+                    extractor_attr.schema_format = Some(input.parse()?);
                 }
-                #[cfg(feature = "unstable_extractor_attr")]
+                #[cfg(feature = "extractor_attr")]
                 ExtractorAttrKey::ContentType => {
-                    let mime = crate::utils::mime::parse_mime(input)?;
-                    match &mut extractor_attr.variant {
-                        ExtractorAttrVariant::Unspecified => {
-                            extractor_attr.variant = ExtractorAttrVariant::BodyExtractor {
-                                content_types: vec![mime],
-                            }
-                        }
-                        ExtractorAttrVariant::PartsExtractor { into_params: _ } => crate::syn_bail!(
-                            ident.span(),
-                            "content_type cannot be defined in an extractor attribute containing into_params"
-                        ),
-                        ExtractorAttrVariant::BodyExtractor { content_types } => content_types.push(mime),
-                    }
+                    extractor_attr.parse_content_type(input, &ident)?;
                 }
             }
         }
 
+        extractor_attr.check_dependencies()?;
         Ok(extractor_attr)
     }
 }
 
 impl ExtractorAttr {
-    #[cfg_attr(not(feature = "unstable_extractor_attr"), expect(clippy::unused_self))]
+    /// Parse the `rejection_status`, which must be known at compile time.
+    fn parse_rejection_status(input: syn::parse::ParseStream) -> syn::Result<SpannedValue<HttpStatusCode>> {
+        let status: SpannedValue<HttpStatusCode> = input.parse()?;
+        if status.is_runtime() {
+            crate::syn_bail!(status.span(), "rejection_status should be an actual status code");
+        }
+        Ok(status)
+    }
+
+    /// Parse `into_params`, which declares a parts extractor.
+    #[cfg(feature = "extractor_attr")]
+    fn parse_into_params(&mut self, input: syn::parse::ParseStream, ident: &Ident) -> syn::Result<()> {
+        let value = input.parse()?;
+        match &mut self.variant {
+            ExtractorAttrVariant::Unspecified => {
+                self.variant = ExtractorAttrVariant::PartsExtractor { into_params: value }
+            }
+            ExtractorAttrVariant::PartsExtractor { into_params } => *into_params = value,
+            ExtractorAttrVariant::BodyExtractor { content_types: _ } => crate::syn_bail!(
+                ident.span(),
+                "into_params cannot be defined in an extractor attribute containing content_type"
+            ),
+        }
+        Ok(())
+    }
+
+    /// Parse the `schema_type`, which must be one of the openapi types.
+    #[cfg(feature = "extractor_attr")]
+    fn parse_schema_type(input: syn::parse::ParseStream) -> syn::Result<LitStr> {
+        let schema_type: LitStr = input.parse()?;
+        if !SCHEMA_TYPES.contains(&schema_type.value().as_str()) {
+            crate::syn_bail!(
+                schema_type.span(),
+                "unexpected schema_type, should be one of: {}",
+                SCHEMA_TYPES.join(", ")
+            );
+        }
+        Ok(schema_type)
+    }
+
+    /// Parse a `content_type`, which declares a body extractor (can be defined multiple times).
+    #[cfg(feature = "extractor_attr")]
+    fn parse_content_type(&mut self, input: syn::parse::ParseStream, ident: &Ident) -> syn::Result<()> {
+        let mime = crate::utils::mime::parse_mime(input)?;
+        match &mut self.variant {
+            ExtractorAttrVariant::Unspecified => {
+                self.variant = ExtractorAttrVariant::BodyExtractor {
+                    content_types: vec![mime],
+                }
+            }
+            ExtractorAttrVariant::PartsExtractor { into_params: _ } => crate::syn_bail!(
+                ident.span(),
+                "content_type cannot be defined in an extractor attribute containing into_params"
+            ),
+            ExtractorAttrVariant::BodyExtractor { content_types } => content_types.push(mime),
+        }
+        Ok(())
+    }
+
+    /// Check that the parameters depending on another one are not defined alone.
+    fn check_dependencies(&self) -> syn::Result<()> {
+        if self.rejection_status.is_none()
+            && let Some(body) = &self.rejection_body
+        {
+            crate::syn_bail!(body.span(), "rejection_body cannot be defined without rejection_status");
+        }
+        #[cfg(feature = "extractor_attr")]
+        if self.schema_type.is_none()
+            && let Some(schema_format) = &self.schema_format
+        {
+            crate::syn_bail!(
+                schema_format.span(),
+                "schema_format cannot be defined without schema_type"
+            );
+        }
+        #[cfg(feature = "extractor_attr")]
+        if let (Some(schema_type), ExtractorAttrVariant::Unspecified) = (&self.schema_type, &self.variant) {
+            crate::syn_bail!(
+                schema_type.span(),
+                "schema_type cannot be defined without into_params or content_type"
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(not(feature = "extractor_attr"), expect(clippy::unused_self))]
     pub(crate) fn is_parts_extractor(&self) -> bool {
-        #[cfg(feature = "unstable_extractor_attr")]
+        #[cfg(feature = "extractor_attr")]
         if matches!(self.variant, ExtractorAttrVariant::BodyExtractor { .. }) {
             return false;
         }
@@ -134,7 +278,7 @@ impl ExtractorAttr {
             return do_trace.value;
         }
 
-        #[cfg(feature = "unstable_extractor_attr")]
+        #[cfg(feature = "extractor_attr")]
         // if do_trace was not specified, default behavior is to trace if displayed in openapi spec
         match &self.variant {
             ExtractorAttrVariant::PartsExtractor { into_params } => into_params.value,
@@ -142,13 +286,13 @@ impl ExtractorAttr {
             ExtractorAttrVariant::Unspecified => false,
         }
 
-        #[cfg(not(feature = "unstable_extractor_attr"))]
+        #[cfg(not(feature = "extractor_attr"))]
         false
     }
 
-    #[cfg_attr(not(feature = "unstable_extractor_attr"), expect(clippy::unused_self))]
+    #[cfg_attr(not(feature = "extractor_attr"), expect(clippy::unused_self))]
     pub(crate) fn to_add_in_params(&self) -> bool {
-        #[cfg(feature = "unstable_extractor_attr")]
+        #[cfg(feature = "extractor_attr")]
         if let ExtractorAttrVariant::PartsExtractor { into_params } = &self.variant {
             return into_params.value;
         }
@@ -156,9 +300,9 @@ impl ExtractorAttr {
         false
     }
 
-    #[cfg_attr(not(feature = "unstable_extractor_attr"), expect(clippy::unused_self))]
+    #[cfg_attr(not(feature = "extractor_attr"), expect(clippy::unused_self))]
     pub(crate) fn content_types(&self) -> Vec<String> {
-        #[cfg(feature = "unstable_extractor_attr")]
+        #[cfg(feature = "extractor_attr")]
         if let ExtractorAttrVariant::BodyExtractor { content_types } = &self.variant {
             return content_types.iter().map(ToString::to_string).collect();
         }
@@ -166,6 +310,26 @@ impl ExtractorAttr {
         Vec::new()
     }
 
+    /// The openapi schema overriding the one of the extracted type, if `schema_type` is set.
+    #[cfg(feature = "extractor_attr")]
+    pub(crate) fn schema_override(&self) -> Option<proc_macro2::TokenStream> {
+        use convert_case::{Case, Casing};
+
+        let schema_type = self.schema_type.as_ref()?;
+        let type_ident = Ident::new(&schema_type.value().to_case(Case::Pascal), schema_type.span());
+        let format = self.schema_format.as_ref().map(|format| {
+            quote! {.format(Some(utoipa::openapi::schema::SchemaFormat::Custom(#format.to_string())))}
+        });
+        Some(quote! {
+            utoipa::openapi::RefOr::T(utoipa::openapi::schema::Schema::Object(
+                utoipa::openapi::schema::ObjectBuilder::new()
+                    .schema_type(utoipa::openapi::schema::Type::#type_ident)
+                    #format
+                    .build(),
+            ))
+        })
+    }
+
     /// Parse the extractor from a function input parameter
     pub(crate) fn parse_fn_arg(fnarg: &PatType) -> syn::Result<Self> {
         for attr in &fnarg.attrs {
@@ -6,6 +6,7 @@ use syn::spanned::Spanned;
 use syn::{FnArg, GenericArgument, Ident, ItemFn, Pat, PatType, PathArguments, Type, TypePath, parse_quote_spanned};
 
 use crate::args::extractor_attr::ExtractorAttr;
+use crate::utils::http::HttpStatusCode;
 use crate::utils::spanned::SpannedValue;
 use crate::{syn_bail, syn_error};
 
@@ -14,25 +15,66 @@ use crate::{syn_bail, syn_error};
 pub(crate) enum AutorouteAxumExtractorType {
     #[strum(disabled)]
     /// An extractor that is not known
-    Unknown {
-        #[cfg_attr(not(feature = "tracing"), expect(unused))]
-        ty: Ident,
-    },
+    Unknown { ty: Ident },
     /// The axum extractor to extract a json struct from the request body
     #[strum(serialize = "Json")]
     JsonBody,
     /// An `axum::body::Body` without any extraction perfomed on it
     #[strum(serialize = "Body")]
     RawBody,
+    /// An `axum::body::Bytes` containing the whole request body
+    #[strum(serialize = "Bytes")]
+    BytesBody,
     /// Extractor from axum_typed_multipart to extract multipart data from the request body into a struct
     #[strum(serialize = "TypedMultipart")]
     TypedMultipartBody,
+    /// The axum extractor to process the fields of a multipart body one by one, without a predefined structure
+    #[strum(serialize = "Multipart")]
+    RawMultipartBody,
+    /// The axum extractor to extract an url encoded form from the request body
+    #[strum(serialize = "Form")]
+    FormBody,
+    /// The axum extractor to extract the raw bytes of an url encoded form from the request body (or the query for GET requests)
+    #[strum(serialize = "RawForm")]
+    RawFormBody,
+    /// Extractor from axum_extra to stream newline delimited json values from the request body
+    #[cfg(feature = "axum-extra")]
+    #[strum(serialize = "JsonLines")]
+    JsonLinesBody,
     /// Axum extractor to retrieve data from path parameters
     #[strum(serialize = "Path")]
     PathParam,
     /// Axum extractor to retrieve data from query parameters
     #[strum(serialize = "Query")]
     QueryParam,
+    /// Axum extractor to retrieve the original uri of the request (even when called from a nested router)
+    #[strum(serialize = "OriginalUri")]
+    OriginalUri,
+    /// Axum extractor to retrieve the connection info of the client (e.g. its `SocketAddr`)
+    #[strum(serialize = "ConnectInfo")]
+    ConnectInfo,
+    /// Extractor from axum_extra to retrieve the host of the request
+    #[cfg(feature = "axum-extra")]
+    #[strum(serialize = "Host")]
+    Host,
+    /// Axum extractor to retrieve data shared through the request extensions (usually inserted by a middleware)
+    #[strum(serialize = "Extension")]
+    Extension,
+    /// Extractor from axum_extra to retrieve the cookies sent with the request
+    #[cfg(feature = "axum-extra")]
+    #[strum(serialize = "CookieJar")]
+    CookieJarInput,
+    /// Extractor from axum_extra to retrieve the signed cookies sent with the request
+    #[cfg(feature = "axum-extra")]
+    #[strum(serialize = "SignedCookieJar")]
+    SignedCookieJarInput,
+    /// Extractor from axum_extra to retrieve the private (encrypted) cookies sent with the request
+    #[cfg(feature = "axum-extra")]
+    #[strum(serialize = "PrivateCookieJar")]
+    PrivateCookieJarInput,
+    /// Axum extractor to retrieve the path parameters as raw key-value pairs, without deserializing them
+    #[strum(serialize = "RawPathParams")]
+    RawPathParams,
 }
 
 /// Struct describing data detected in the function signature for an axum extractor.
@@ -67,16 +109,64 @@ impl AutorouteAxumExtractor {
             AutorouteAxumExtractorType::Unknown { .. } => self.attr.is_parts_extractor(),
             AutorouteAxumExtractorType::JsonBody
             | AutorouteAxumExtractorType::RawBody
-            | AutorouteAxumExtractorType::TypedMultipartBody => false,
-            AutorouteAxumExtractorType::PathParam | AutorouteAxumExtractorType::QueryParam => true,
+            | AutorouteAxumExtractorType::BytesBody
+            | AutorouteAxumExtractorType::TypedMultipartBody
+            | AutorouteAxumExtractorType::RawMultipartBody
+            | AutorouteAxumExtractorType::FormBody
+            | AutorouteAxumExtractorType::RawFormBody => false,
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::JsonLinesBody => false,
+            AutorouteAxumExtractorType::PathParam
+            | AutorouteAxumExtractorType::QueryParam
+            | AutorouteAxumExtractorType::OriginalUri
+            | AutorouteAxumExtractorType::ConnectInfo
+            | AutorouteAxumExtractorType::Extension
+            | AutorouteAxumExtractorType::RawPathParams => true,
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::Host
+            | AutorouteAxumExtractorType::CookieJarInput
+            | AutorouteAxumExtractorType::SignedCookieJarInput
+            | AutorouteAxumExtractorType::PrivateCookieJarInput => true,
+        }
+    }
+
+    /// The status code and description of the response returned by axum when this extractor fails,
+    /// documented if `auto_document_rejections` is enabled.
+    pub(crate) fn default_rejection(&self) -> Option<(HttpStatusCode, &'static str)> {
+        match *self.extractor_ty {
+            AutorouteAxumExtractorType::PathParam => {
+                Some((HttpStatusCode::BadRequest, "Path parameter parsing failed"))
+            }
+            AutorouteAxumExtractorType::QueryParam => {
+                Some((HttpStatusCode::BadRequest, "Query parameter parsing failed"))
+            }
+            AutorouteAxumExtractorType::TypedMultipartBody | AutorouteAxumExtractorType::RawMultipartBody => {
+                Some((HttpStatusCode::BadRequest, "Multipart body parsing failed"))
+            }
+            AutorouteAxumExtractorType::JsonBody => {
+                Some((HttpStatusCode::UnprocessableEntity, "Json body parsing failed"))
+            }
+            AutorouteAxumExtractorType::FormBody => {
+                Some((HttpStatusCode::UnprocessableEntity, "Form body parsing failed"))
+            }
+            _ => None,
         }
     }
 
     pub(crate) fn content_types(&self) -> syn::Result<Vec<String>> {
         Ok(match *self.extractor_ty {
             AutorouteAxumExtractorType::JsonBody => vec!["application/json".to_string()],
-            AutorouteAxumExtractorType::RawBody => vec!["application/octet-stream".to_string()],
-            AutorouteAxumExtractorType::TypedMultipartBody => vec!["multipart/form-data".to_string()],
+            AutorouteAxumExtractorType::RawBody | AutorouteAxumExtractorType::BytesBody => {
+                vec!["application/octet-stream".to_string()]
+            }
+            AutorouteAxumExtractorType::TypedMultipartBody | AutorouteAxumExtractorType::RawMultipartBody => {
+                vec!["multipart/form-data".to_string()]
+            }
+            AutorouteAxumExtractorType::FormBody | AutorouteAxumExtractorType::RawFormBody => {
+                vec!["application/x-www-form-urlencoded".to_string()]
+            }
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::JsonLinesBody => vec!["application/x-ndjson".to_string()],
             AutorouteAxumExtractorType::Unknown { ty: _ } if !self.attr.content_types().is_empty() => {
                 self.attr.content_types()
             }
@@ -90,8 +180,22 @@ impl AutorouteAxumExtractor {
 
     #[cfg(feature = "tracing")]
     pub(crate) fn to_trace(&self) -> bool {
+        // e.g. `trace=false` for an extractor holding sensitive data
+        if let Some(do_trace) = &self.attr.do_trace {
+            return do_trace.value;
+        }
         match *self.extractor_ty {
             AutorouteAxumExtractorType::Unknown { ty: _ } => self.attr.to_trace(),
+            // the fields have not been read yet, there is nothing meaningful to trace
+            AutorouteAxumExtractorType::RawMultipartBody => false,
+            // the values are streamed, there is nothing meaningful to trace
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::JsonLinesBody => false,
+            // the cookie values are sensitive, they should not be traced
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::SignedCookieJarInput | AutorouteAxumExtractorType::PrivateCookieJarInput => {
+                false
+            }
             _ => true,
         }
     }
@@ -99,16 +203,41 @@ impl AutorouteAxumExtractor {
     pub(crate) fn to_add_in_params(&self) -> bool {
         match *self.extractor_ty {
             AutorouteAxumExtractorType::Unknown { ty: _ } => self.attr.to_add_in_params(),
+            // no openapi representation
+            AutorouteAxumExtractorType::OriginalUri
+            | AutorouteAxumExtractorType::ConnectInfo
+            | AutorouteAxumExtractorType::Extension
+            | AutorouteAxumExtractorType::RawPathParams => false,
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::Host
+            | AutorouteAxumExtractorType::CookieJarInput
+            | AutorouteAxumExtractorType::SignedCookieJarInput
+            | AutorouteAxumExtractorType::PrivateCookieJarInput => false,
             _ => self.is_parts_extractor(),
         }
     }
 
     pub(crate) fn openapi_content(&self) -> syn::Result<Type> {
         Ok(match *self.extractor_ty {
-            AutorouteAxumExtractorType::RawBody => parse_quote_spanned! {self.extracted_ty.span()=> [u8]},
+            AutorouteAxumExtractorType::RawBody
+            | AutorouteAxumExtractorType::BytesBody
+            | AutorouteAxumExtractorType::RawMultipartBody
+            | AutorouteAxumExtractorType::RawFormBody => {
+                parse_quote_spanned! {self.extracted_ty.span()=> [u8]}
+            }
+            // the extracted type may not implement `utoipa::ToSchema` if its schema is overridden,
+            // the placeholder schema is replaced afterward by the operation modifiers
+            #[cfg(feature = "extractor_attr")]
+            AutorouteAxumExtractorType::Unknown { ty: _ } if self.attr.schema_type.is_some() => {
+                parse_quote_spanned! {self.extracted_ty.span()=> String}
+            }
             AutorouteAxumExtractorType::JsonBody
             | AutorouteAxumExtractorType::TypedMultipartBody
+            | AutorouteAxumExtractorType::FormBody
             | AutorouteAxumExtractorType::Unknown { ty: _ } => self.extracted_ty.clone(),
+            // each line of the body is a json value of the extracted type
+            #[cfg(feature = "axum-extra")]
+            AutorouteAxumExtractorType::JsonLinesBody => self.extracted_ty.clone(),
             _ => syn_bail!(
                 self.extractor_ty.span(),
                 "INTERNAL_MACRO_ERROR: no default content implemented for {:?}",
@@ -228,9 +357,14 @@ impl AutorouteAxumExtractor {
         }
     }
 
-    #[cfg_attr(not(feature = "unstable_extractor_attr"), expect(clippy::unnecessary_wraps))]
     fn validate(self) -> syn::Result<Self> {
-        #[cfg(feature = "unstable_extractor_attr")]
+        if self.is_parts_extractor()
+            && let Some((name, _)) = self.attr.examples.first()
+        {
+            syn_bail!(name.span(), "examples can only be defined on a body extractor");
+        }
+
+        #[cfg(feature = "extractor_attr")]
         {
             static KNOWN_EXTRACTOR_TYPES: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {
                 <AutorouteAxumExtractorType as strum::IntoEnumIterator>::iter()
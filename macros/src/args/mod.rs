@@ -1,33 +1,58 @@
 use std::str::FromStr;
 
+use callbacks::AutorouteCallback;
+use external_docs::AutorouteExternalDocs;
 use extractors::AutorouteAxumExtractor;
+use proc_macro2::TokenTree;
+use quote::quote;
 use responses::AutorouteResponse;
+use security::AutorouteSecurityRequirement;
 use strum::IntoEnumIterator;
 use syn::parse::{Parse, ParseStream};
-use syn::{Ident, ItemFn, LitStr, Token, bracketed, parse2};
+use syn::spanned::Spanned;
+use syn::{
+    GenericArgument, Ident, ItemFn, LitBool, LitStr, PathArguments, ReturnType, Token, Type, bracketed, parenthesized,
+    parse2,
+};
 
 use crate::syn_bail;
-use crate::utils::error::syn_error;
-use crate::utils::http::HttpMethod;
+use crate::utils::http::{HttpMethod, HttpStatusCode};
 use crate::utils::parse_named_ident;
 use crate::utils::spanned::SpannedValue;
 
+pub(crate) mod autoroute_test;
+pub(crate) mod callbacks;
+pub(crate) mod external_docs;
 pub(crate) mod extractor_attr;
 pub(crate) mod extractors;
+pub(crate) mod module_openapi;
 pub(crate) mod responses;
+pub(crate) mod security;
 
 /// Enum listing the different non-positional parameters of the `autoroute` macro.
-#[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
 #[strum(serialize_all = "snake_case")]
 pub(crate) enum AutorouteMetaKey {
     Responses,
     Tags,
+    Security,
+    Summary,
+    OperationId,
+    Deprecated,
+    ExternalDocs,
+    AutoDocumentRejections,
+    EnumName,
+    TraceDuration,
+    OkStatus,
+    ErrStatus,
+    ParamsDescription,
+    Callbacks,
 }
 
 /// Struct holding data extracted from the `autoroute` macro arguments.
 pub(crate) struct AutorouteMeta {
-    /// The HTTP method to use.
-    pub(crate) method: SpannedValue<HttpMethod>,
+    /// The HTTP methods handled by the route (at least one).
+    pub(crate) methods: Vec<SpannedValue<HttpMethod>>,
     /// The path of the route.
     pub(crate) path: LitStr,
     /// The list of possible responses returned by the route.
@@ -35,41 +60,83 @@ pub(crate) struct AutorouteMeta {
     /// The tags of the route.
     /// Used in openapi documentation and by swagger-ui to group routes.
     pub(crate) tags: Vec<LitStr>,
+    /// The security requirements of the route for the openapi documentation.
+    pub(crate) security: Option<Vec<AutorouteSecurityRequirement>>,
+    /// An optional summary of the route for the openapi documentation.
+    /// If not set, utoipa will use the first line of the function doc comment.
+    pub(crate) summary: Option<LitStr>,
+    /// An optional operation id of the route for the openapi documentation.
+    /// If not set, utoipa will use the function name.
+    pub(crate) operation_id: Option<LitStr>,
+    /// Indicates whether the route should be marked as deprecated in the openapi documentation.
+    pub(crate) deprecated: bool,
+    /// An optional link to an external documentation of the route.
+    pub(crate) external_docs: Option<AutorouteExternalDocs>,
+    /// Indicates whether the responses automatically returned by axum when an extractor fails
+    /// should be added to the openapi documentation.
+    pub(crate) auto_document_rejections: bool,
+    /// An optional name for the generated responses enum.
+    /// If not set, the name is derived from the function name.
+    pub(crate) enum_name: Option<LitStr>,
+    /// Indicates whether the duration of the handler should be traced if the `tracing` feature is enabled.
+    pub(crate) trace_duration: bool,
+    /// The status codes of the responses associated with the `Ok` and `Err` types of a function returning a `Result`.
+    /// If set, the responses are built from the function return type instead of the `responses` field.
+    pub(crate) result_statuses: Option<(SpannedValue<HttpStatusCode>, SpannedValue<HttpStatusCode>)>,
+    /// Descriptions of the parameters (name and description) overriding the ones of the openapi documentation.
+    pub(crate) params_description: Vec<(LitStr, LitStr)>,
+    /// The callbacks (out-of-band requests sent by the API) of the route for the openapi documentation.
+    pub(crate) callbacks: Vec<AutorouteCallback>,
 }
 
 impl std::fmt::Debug for AutorouteMeta {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Self {
-            method,
+            methods,
             path,
             responses,
             tags,
+            security,
+            summary,
+            operation_id,
+            deprecated,
+            external_docs,
+            auto_document_rejections,
+            enum_name,
+            trace_duration,
+            result_statuses,
+            params_description,
+            callbacks,
         } = self;
         f.debug_struct("AutorouteResponse")
-            .field("method", method)
+            .field("methods", methods)
             .field("path", &path.value())
             .field("tags", &tags.iter().map(LitStr::value).collect::<Vec<_>>())
             .field("responses", responses)
+            .field("security", security)
+            .field("summary", &summary.as_ref().map(LitStr::value))
+            .field("operation_id", &operation_id.as_ref().map(LitStr::value))
+            .field("deprecated", deprecated)
+            .field("external_docs", external_docs)
+            .field("auto_document_rejections", auto_document_rejections)
+            .field("enum_name", &enum_name.as_ref().map(LitStr::value))
+            .field("trace_duration", trace_duration)
+            .field("result_statuses", result_statuses)
+            .field(
+                "params_description",
+                &params_description
+                    .iter()
+                    .map(|(name, description)| (name.value(), description.value()))
+                    .collect::<Vec<_>>(),
+            )
+            .field("callbacks", callbacks)
             .finish_non_exhaustive()
     }
 }
 
 impl Parse for AutorouteMeta {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let key_error = |span| {
-            syn::Error::new(
-                span,
-                format!(
-                    "expected one of: {}",
-                    AutorouteMetaKey::iter()
-                        .map(|key| key.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                ),
-            )
-        };
-
-        let method = input.parse()?;
+        let methods = Self::parse_methods(input)?;
         input.parse::<Token![,]>()?;
 
         parse_named_ident(input, "path")?;
@@ -79,6 +146,19 @@ impl Parse for AutorouteMeta {
         // parse unordered args
         let mut responses = None;
         let mut tags = None;
+        let mut security = None;
+        let mut summary = None;
+        let mut operation_id = None;
+        let mut deprecated = None;
+        let mut external_docs = None;
+        let mut auto_document_rejections = None;
+        let mut enum_name = None;
+        let mut trace_duration = None;
+        let mut ok_status = None;
+        let mut err_status = None;
+        let mut params_description = None;
+        let mut callbacks = None;
+        let mut defined_keys = Vec::new();
         while !input.is_empty() {
             // allow trailing comma
             input.parse::<Token![,]>()?;
@@ -86,46 +166,290 @@ impl Parse for AutorouteMeta {
                 break;
             }
 
-            let ident: Ident = input.parse().map_err(|e| key_error(e.span()))?;
-            let key = AutorouteMetaKey::from_str(&ident.to_string()).map_err(|_| key_error(ident.span()))?;
+            let ident: Ident = input
+                .parse()
+                .map_err(|e| AutorouteMetaKey::unexpected_key_error(e.span()))?;
+            let key = AutorouteMetaKey::from_str(&ident.to_string())
+                .map_err(|_| AutorouteMetaKey::unexpected_key_error(ident.span()))?;
+            if defined_keys.contains(&key) {
+                syn_bail!(ident.span(), "{} already defined", key.to_string());
+            }
+            defined_keys.push(key);
             input.parse::<Token![=]>()?;
             match key {
                 AutorouteMetaKey::Responses => {
-                    if responses.is_some() {
-                        syn_bail!(ident.span(), "{} already defined", key.to_string());
-                    }
-                    let content;
-                    let brackets = bracketed!(content in input);
-                    let punctuated = content.parse_terminated(AutorouteResponse::parse, Token![,])?;
-                    if punctuated.is_empty() {
-                        syn_bail!(ident.span(), "at least one response is required");
-                    }
-                    responses = Some(SpannedValue::new(
-                        punctuated.into_iter().collect(),
-                        brackets.span.join(),
-                    ));
+                    responses = Some(Self::parse_responses(input, &ident)?);
                 }
                 AutorouteMetaKey::Tags => {
-                    if tags.is_some() {
-                        syn_bail!(ident.span(), "{} already defined", key.to_string());
-                    }
-                    let content;
-                    bracketed!(content in input);
-                    let punctuated = content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
-                    tags = Some(punctuated.into_iter().collect());
+                    tags = Some(Self::parse_tags(input)?);
+                }
+                AutorouteMetaKey::Security => {
+                    security = Some(Self::parse_security(input)?);
+                }
+                AutorouteMetaKey::Summary => {
+                    summary = Some(input.parse()?);
+                }
+                AutorouteMetaKey::OperationId => {
+                    operation_id = Some(input.parse()?);
+                }
+                AutorouteMetaKey::Deprecated => {
+                    deprecated = Some(input.parse::<LitBool>()?.value);
+                }
+                AutorouteMetaKey::ExternalDocs => {
+                    external_docs = Some(input.parse()?);
+                }
+                AutorouteMetaKey::AutoDocumentRejections => {
+                    auto_document_rejections = Some(input.parse::<LitBool>()?.value);
+                }
+                AutorouteMetaKey::EnumName => {
+                    enum_name = Some(Self::parse_enum_name(input)?);
+                }
+                AutorouteMetaKey::TraceDuration => {
+                    trace_duration = Some(input.parse::<LitBool>()?.value);
+                }
+                AutorouteMetaKey::OkStatus => {
+                    ok_status = Some(Self::parse_result_status(input, key)?);
+                }
+                AutorouteMetaKey::ErrStatus => {
+                    err_status = Some(Self::parse_result_status(input, key)?);
+                }
+                AutorouteMetaKey::ParamsDescription => {
+                    params_description = Some(Self::parse_params_description(input)?);
+                }
+                AutorouteMetaKey::Callbacks => {
+                    callbacks = Some(Self::parse_callbacks(input)?);
                 }
             }
         }
 
+        let (responses, result_statuses) = Self::check_responses(input, responses, ok_status, err_status)?;
+
         Ok(AutorouteMeta {
-            method,
+            methods,
             path,
-            responses: responses.ok_or(syn_error!(input.span(), "no {} defined", AutorouteMetaKey::Responses))?,
+            responses,
             tags: tags.unwrap_or_default(),
+            security,
+            summary,
+            operation_id,
+            deprecated: deprecated.unwrap_or_default(),
+            external_docs,
+            auto_document_rejections: auto_document_rejections.unwrap_or_default(),
+            enum_name,
+            trace_duration: trace_duration.unwrap_or_default(),
+            result_statuses,
+            params_description: params_description.unwrap_or_default(),
+            callbacks: callbacks.unwrap_or_default(),
         })
     }
 }
 
+impl AutorouteMetaKey {
+    fn unexpected_key_error(span: proc_macro2::Span) -> syn::Error {
+        syn::Error::new(
+            span,
+            format!(
+                "expected one of: {}",
+                Self::iter().map(|key| key.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+        )
+    }
+}
+
+impl AutorouteMeta {
+    /// Parse the list of responses (`[(...), ...]`), `key` being the ident of the field.
+    fn parse_responses(input: ParseStream, key: &Ident) -> syn::Result<SpannedValue<Vec<AutorouteResponse>>> {
+        let content;
+        let brackets = bracketed!(content in input);
+        let punctuated = content.parse_terminated(AutorouteResponse::parse, Token![,])?;
+        if punctuated.is_empty() {
+            syn_bail!(key.span(), "at least one response is required");
+        }
+        Ok(SpannedValue::new(
+            punctuated.into_iter().collect(),
+            brackets.span.join(),
+        ))
+    }
+
+    /// Parse the list of tags (e.g. `["tag1", "tag2"]`).
+    fn parse_tags(input: ParseStream) -> syn::Result<Vec<LitStr>> {
+        let content;
+        bracketed!(content in input);
+        let punctuated = content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+        Ok(punctuated.into_iter().collect())
+    }
+
+    /// Parse the list of security requirements.
+    fn parse_security(input: ParseStream) -> syn::Result<Vec<AutorouteSecurityRequirement>> {
+        let content;
+        bracketed!(content in input);
+        let punctuated = content.parse_terminated(AutorouteSecurityRequirement::parse, Token![,])?;
+        Ok(punctuated.into_iter().collect())
+    }
+
+    /// Check that either the responses, or both the `Ok` and `Err` status codes are defined.
+    #[expect(clippy::type_complexity)]
+    fn check_responses(
+        input: ParseStream,
+        responses: Option<SpannedValue<Vec<AutorouteResponse>>>,
+        ok_status: Option<SpannedValue<HttpStatusCode>>,
+        err_status: Option<SpannedValue<HttpStatusCode>>,
+    ) -> syn::Result<(
+        SpannedValue<Vec<AutorouteResponse>>,
+        Option<(SpannedValue<HttpStatusCode>, SpannedValue<HttpStatusCode>)>,
+    )> {
+        match (responses, ok_status, err_status) {
+            (Some(responses), None, None) => Ok((responses, None)),
+            // the responses are built afterward from the function return type
+            (None, Some(ok_status), Some(err_status)) => Ok((
+                SpannedValue::new(Vec::new(), ok_status.span()),
+                Some((ok_status, err_status)),
+            )),
+            (Some(_), Some(status), _) | (Some(_), None, Some(status)) => syn_bail!(
+                status.span(),
+                "{} and {} cannot be used along with {}",
+                AutorouteMetaKey::OkStatus,
+                AutorouteMetaKey::ErrStatus,
+                AutorouteMetaKey::Responses
+            ),
+            (None, Some(status), None) => syn_bail!(
+                status.span(),
+                "{} is required along with {}",
+                AutorouteMetaKey::ErrStatus,
+                AutorouteMetaKey::OkStatus
+            ),
+            (None, None, Some(status)) => syn_bail!(
+                status.span(),
+                "{} is required along with {}",
+                AutorouteMetaKey::OkStatus,
+                AutorouteMetaKey::ErrStatus
+            ),
+            (None, None, None) => syn_bail!(input.span(), "no {} defined", AutorouteMetaKey::Responses),
+        }
+    }
+
+    /// Parse the name of the responses enum, which must be a valid identifier.
+    fn parse_enum_name(input: ParseStream) -> syn::Result<LitStr> {
+        let name: LitStr = input.parse()?;
+        if syn::parse_str::<Ident>(&name.value()).is_err() {
+            syn_bail!(
+                name.span(),
+                "{} should be a valid identifier",
+                AutorouteMetaKey::EnumName
+            );
+        }
+        Ok(name)
+    }
+
+    /// Parse the status code of the `Ok` or `Err` response (`key` being the field),
+    /// which must be known at compile time.
+    fn parse_result_status(input: ParseStream, key: AutorouteMetaKey) -> syn::Result<SpannedValue<HttpStatusCode>> {
+        let status: SpannedValue<HttpStatusCode> = input.parse()?;
+        if status.is_runtime() {
+            syn_bail!(status.span(), "{key} cannot be a `DEFAULT` or range status code");
+        }
+        Ok(status)
+    }
+
+    /// Parse the list of parameters descriptions (`[("name", "description"), ...]`), each parameter being described once.
+    fn parse_params_description(input: ParseStream) -> syn::Result<Vec<(LitStr, LitStr)>> {
+        let content;
+        bracketed!(content in input);
+        let mut params_description: Vec<(LitStr, LitStr)> = Vec::new();
+        while !content.is_empty() {
+            let param;
+            parenthesized!(param in content);
+            let name: LitStr = param.parse()?;
+            param.parse::<Token![,]>()?;
+            let description: LitStr = param.parse()?;
+            if !param.is_empty() {
+                param.parse::<Token![,]>()?;
+            }
+            if params_description
+                .iter()
+                .any(|(other, _)| other.value() == name.value())
+            {
+                syn_bail!(name.span(), "parameter `{}` already described", name.value());
+            }
+            params_description.push((name, description));
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+        Ok(params_description)
+    }
+
+    /// Parse the list of callbacks: `[("name", METHOD, "url", body=..., responses=[...]), ...]`.
+    fn parse_callbacks(input: ParseStream) -> syn::Result<Vec<AutorouteCallback>> {
+        let content;
+        bracketed!(content in input);
+        let punctuated = content.parse_terminated(AutorouteCallback::parse, Token![,])?;
+        let mut callbacks: Vec<AutorouteCallback> = Vec::new();
+        for callback in punctuated {
+            if callbacks
+                .iter()
+                .any(|other| other.name.value() == callback.name.value())
+            {
+                syn_bail!(
+                    callback.name.span(),
+                    "callback `{}` already declared",
+                    callback.name.value()
+                );
+            }
+            callbacks.push(callback);
+        }
+        Ok(callbacks)
+    }
+
+    /// Declare the responses associated with the `Ok` and `Err` types of the function return type if `ok_status` and `err_status` are used.
+    /// Returns these `Ok` and `Err` types.
+    fn declare_result_responses(&mut self, itemfn: &ItemFn) -> syn::Result<Option<(Type, Type)>> {
+        let Some((ok_status, err_status)) = self.result_statuses else {
+            return Ok(None);
+        };
+        let Some((ok_type, err_type)) = result_types(&itemfn.sig.output) else {
+            let err_span = match &itemfn.sig.output {
+                ReturnType::Type(_, ty) => ty.span(),
+                ReturnType::Default => itemfn.sig.output.span(),
+            };
+            syn_bail!(
+                err_span,
+                "expecting return type `Result<T, E>` when {} and {} are used",
+                AutorouteMetaKey::OkStatus,
+                AutorouteMetaKey::ErrStatus
+            );
+        };
+        self.responses.push(AutorouteResponse::new(ok_status, ok_type.clone())?);
+        self.responses
+            .push(AutorouteResponse::new(err_status, err_type.clone())?);
+        Ok(Some((ok_type, err_type)))
+    }
+
+    /// Parse either a single method (`GET`) or a list of methods (`methods=[GET, POST]`).
+    fn parse_methods(input: ParseStream) -> syn::Result<Vec<SpannedValue<HttpMethod>>> {
+        if parse_named_ident(input, "methods").is_err() {
+            return Ok(vec![input.parse()?]);
+        }
+
+        input.parse::<Token![=]>()?;
+        let content;
+        let brackets = bracketed!(content in input);
+        let punctuated = content.parse_terminated(<SpannedValue<HttpMethod> as Parse>::parse, Token![,])?;
+        let mut methods: Vec<SpannedValue<HttpMethod>> = Vec::new();
+        for method in punctuated {
+            if methods.iter().any(|defined| **defined == *method) {
+                syn_bail!(method.span(), "method {} already defined", *method);
+            }
+            methods.push(method);
+        }
+        if methods.is_empty() {
+            syn_bail!(brackets.span.join(), "at least one method should be defined");
+        }
+        Ok(methods)
+    }
+}
+
 /// Data extracted from the `autoroute` macro call.
 pub(crate) struct AutorouteInput {
     /// Data extracted from the macro parameters.
@@ -134,6 +458,8 @@ pub(crate) struct AutorouteInput {
     pub(crate) axum_extractors: Vec<AutorouteAxumExtractor>,
     /// The target function item.
     pub(crate) itemfn: ItemFn,
+    /// The `Ok` and `Err` types of the function return type if `ok_status` and `err_status` are used.
+    pub(crate) result_types: Option<(Type, Type)>,
 }
 
 impl std::fmt::Debug for AutorouteInput {
@@ -148,25 +474,81 @@ impl std::fmt::Debug for AutorouteInput {
 impl AutorouteInput {
     pub(crate) fn build(meta_args: proc_macro2::TokenStream, item: proc_macro2::TokenStream) -> syn::Result<Self> {
         let itemfn: ItemFn = parse2(item)?;
-        let meta: AutorouteMeta = parse2(meta_args)?;
+        Self::check_free_function(&itemfn)?;
+        let mut meta: AutorouteMeta = parse2(meta_args)?;
+        let result_types = meta.declare_result_responses(&itemfn)?;
         let axum_extractors = AutorouteAxumExtractor::parse_many(&itemfn)?;
 
         Ok(Self {
             meta,
             axum_extractors,
             itemfn,
+            result_types,
         })
     }
 
+    /// Check that the target function does not look like an associated function of an `impl` block
+    /// (i.e. it has no `self` receiver and does not use `Self` in its signature),
+    /// as the items declared by the macro next to the function cannot be declared in an `impl` block.
+    fn check_free_function(itemfn: &ItemFn) -> syn::Result<()> {
+        if let Some(receiver) = itemfn.sig.receiver() {
+            syn_bail!(
+                receiver.self_token.span(),
+                "autoroute handlers must be free functions and cannot take `self`, \
+                 use a `State` extractor (e.g. `State<Arc<MyController>>`) to access shared data instead"
+            );
+        }
+        for fnarg in &itemfn.sig.inputs {
+            if let Some(self_ident) = find_self_type(quote! {#fnarg}) {
+                syn_bail!(
+                    self_ident.span(),
+                    "autoroute handlers must be free functions and cannot use `Self`, \
+                     use the type name instead and declare the handler outside of the `impl` block"
+                );
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn fn_ident(&self) -> Ident {
         self.itemfn.sig.ident.clone()
     }
 
-    pub(crate) fn method(&self) -> SpannedValue<HttpMethod> {
-        self.meta.method
+    pub(crate) fn methods(&self) -> &[SpannedValue<HttpMethod>] {
+        &self.meta.methods
     }
 
     pub(crate) fn path(&self) -> LitStr {
         self.meta.path.clone()
     }
 }
+
+/// Extract the `Ok` and `Err` types of a `Result<T, E>` return type.
+fn result_types(output: &ReturnType) -> Option<(Type, Type)> {
+    let ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let Type::Path(path) = &**ty else {
+        return None;
+    };
+    let last_segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(generic_args) = &last_segment.arguments else {
+        return None;
+    };
+    if last_segment.ident != "Result" || generic_args.args.len() != 2 {
+        return None;
+    }
+    match (&generic_args.args[0], &generic_args.args[1]) {
+        (GenericArgument::Type(ok_type), GenericArgument::Type(err_type)) => Some((ok_type.clone(), err_type.clone())),
+        _ => None,
+    }
+}
+
+/// Find the first `Self` identifier in a token stream (including nested groups).
+fn find_self_type(tokens: proc_macro2::TokenStream) -> Option<Ident> {
+    tokens.into_iter().find_map(|token| match token {
+        TokenTree::Ident(ident) if ident == "Self" => Some(ident),
+        TokenTree::Group(group) => find_self_type(group.stream()),
+        _ => None,
+    })
+}
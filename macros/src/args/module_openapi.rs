@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token, bracketed};
+
+use crate::utils::error::syn_bail;
+
+/// Enum listing the different parameters of the `module_openapi` macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum ModuleOpenapiKey {
+    Tags,
+}
+
+/// Struct holding data extracted from the `module_openapi` macro arguments.
+pub(crate) struct ModuleOpenapiMeta {
+    /// The tags added to every handler of the module.
+    pub(crate) tags: Vec<LitStr>,
+}
+
+impl Parse for ModuleOpenapiMeta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key_error = |span| {
+            syn::Error::new(
+                span,
+                format!(
+                    "expected one of: {}",
+                    ModuleOpenapiKey::iter()
+                        .map(|key| key.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        };
+
+        let mut tags = None;
+        let mut is_first = true;
+        while !input.is_empty() {
+            if is_first {
+                is_first = false;
+            } else {
+                // allow trailing comma
+                input.parse::<Token![,]>()?;
+                if input.is_empty() {
+                    break;
+                }
+            }
+
+            let ident: Ident = input.parse().map_err(|e| key_error(e.span()))?;
+            let key = ModuleOpenapiKey::from_str(&ident.to_string()).map_err(|_| key_error(ident.span()))?;
+            input.parse::<Token![=]>()?;
+            match key {
+                ModuleOpenapiKey::Tags => {
+                    if tags.is_some() {
+                        syn_bail!(ident.span(), "{} already defined", key.to_string());
+                    }
+                    let content;
+                    bracketed!(content in input);
+                    let punctuated = content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+                    tags = Some(punctuated.into_iter().collect());
+                }
+            }
+        }
+
+        let Some(tags) = tags else {
+            syn_bail!(proc_macro2::Span::call_site(), "no {} defined", ModuleOpenapiKey::Tags);
+        };
+        Ok(Self { tags })
+    }
+}
@@ -7,13 +7,14 @@ use quote::quote;
 use strum::IntoEnumIterator;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Ident, LitBool, LitStr, Token, Type, TypePath, bracketed, parenthesized};
+use syn::{Expr, Ident, LitBool, LitStr, Token, Type, TypePath, bracketed, parenthesized};
 
 use crate::syn_bail;
 use crate::utils::http::{HttpStatusCode, parse_header_name};
 use crate::utils::mime::parse_mime;
 use crate::utils::parse_named_ident;
 use crate::utils::spanned::SpannedValue;
+use crate::utils::trace_level::TraceLevel;
 
 /// If the type is a tuple, split it so that only the last one will be the response body content.
 /// The rest should be response parts.
@@ -55,8 +56,14 @@ pub(crate) struct AutorouteResponse {
     pub(crate) headers: Vec<AutorouteResponseHeader>,
     /// The optional description of the response.
     pub(crate) description: Option<LitStr>,
+    /// An optional example of the response body (any expression implementing `serde::Serialize`).
+    pub(crate) example: Option<Expr>,
+    /// A list of named examples of the response body (any expressions implementing `serde::Serialize`).
+    pub(crate) examples: Vec<(LitStr, Expr)>,
     /// Indicates whether this response should be traced or not.
     pub(crate) do_trace: bool,
+    /// The level of the response trace.
+    pub(crate) trace_level: TraceLevel,
     pub(crate) span: Span,
 }
 
@@ -70,7 +77,10 @@ impl std::fmt::Debug for AutorouteResponse {
             serializer,
             headers,
             description,
+            example,
+            examples,
             do_trace,
+            trace_level,
             span: _,
         } = self;
         f.debug_struct("AutorouteResponse")
@@ -81,11 +91,46 @@ impl std::fmt::Debug for AutorouteResponse {
             .field("serializer", serializer)
             .field("headers", headers)
             .field("description", &description.as_ref().map(LitStr::value))
+            .field(
+                "example",
+                &example.as_ref().map(|example| quote! {#example}.to_string()),
+            )
+            .field(
+                "examples",
+                &examples
+                    .iter()
+                    .map(|(name, example)| (name.value(), quote! {#example}.to_string()))
+                    .collect::<Vec<_>>(),
+            )
             .field("do_trace", do_trace)
+            .field("trace_level", trace_level)
             .finish_non_exhaustive()
     }
 }
 
+impl AutorouteResponse {
+    /// Create a response with the default options from its status code and returned type
+    /// (e.g. for the responses associated with the `Ok` and `Err` types of a `Result`).
+    pub(crate) fn new(status_code: SpannedValue<HttpStatusCode>, return_type: Type) -> syn::Result<Self> {
+        let span = return_type.span();
+        let (body, parts) = split_into_body_and_parts(return_type)?;
+        Ok(Self {
+            status_code,
+            body,
+            parts,
+            content_type: None,
+            serializer: AutorouteResponseSerializer::Default,
+            headers: Vec::new(),
+            description: None,
+            example: None,
+            examples: Vec::new(),
+            do_trace: true,
+            trace_level: TraceLevel::default(),
+            span,
+        })
+    }
+}
+
 /// Enum listing the different non-positional parameters of the responses.
 #[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::EnumIter)]
 #[strum(serialize_all = "snake_case")]
@@ -94,7 +139,10 @@ pub(crate) enum AutorouteResponseKey {
     Serializer,
     Headers,
     Description,
+    Example,
+    Examples,
     Trace,
+    TraceLevel,
 }
 
 impl Parse for AutorouteResponse {
@@ -128,7 +176,10 @@ impl Parse for AutorouteResponse {
         let mut content_type = None;
         let mut serializer = AutorouteResponseSerializer::Default;
         let mut description = None;
+        let mut example = None;
+        let mut examples = Vec::new();
         let mut do_trace = true;
+        let mut trace_level = TraceLevel::default();
         while !content.is_empty() {
             // allow trailing comma
             content.parse::<Token![,]>()?;
@@ -155,9 +206,18 @@ impl Parse for AutorouteResponse {
                 AutorouteResponseKey::Description => {
                     description = Some(content.parse()?);
                 }
+                AutorouteResponseKey::Example => {
+                    example = Some(content.parse()?);
+                }
+                AutorouteResponseKey::Examples => {
+                    examples = parse_examples(&content)?;
+                }
                 AutorouteResponseKey::Trace => {
                     do_trace = content.parse::<LitBool>()?.value;
                 }
+                AutorouteResponseKey::TraceLevel => {
+                    trace_level = content.parse()?;
+                }
             }
         }
 
@@ -169,12 +229,41 @@ impl Parse for AutorouteResponse {
             serializer,
             headers,
             description,
+            example,
+            examples,
             do_trace,
+            trace_level,
             span: parentheses.span.join(),
         })
     }
 }
 
+/// Parse a list of named examples: `[("name", value), ...]`.
+pub(crate) fn parse_examples(input: ParseStream) -> syn::Result<Vec<(LitStr, Expr)>> {
+    let content;
+    bracketed!(content in input);
+    let mut examples: Vec<(LitStr, Expr)> = Vec::new();
+    while !content.is_empty() {
+        let example;
+        parenthesized!(example in content);
+        let name: LitStr = example.parse()?;
+        example.parse::<Token![,]>()?;
+        let value: Expr = example.parse()?;
+        if !example.is_empty() {
+            example.parse::<Token![,]>()?;
+        }
+        if examples.iter().any(|(other, _)| other.value() == name.value()) {
+            syn_bail!(name.span(), "example `{}` already declared", name.value());
+        }
+        examples.push((name, value));
+
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(examples)
+}
+
 /// The serializer called on the response body type.
 #[derive(Clone)]
 pub(crate) enum AutorouteResponseSerializer {
@@ -182,15 +271,29 @@ pub(crate) enum AutorouteResponseSerializer {
     Default,
     /// No serializer, the reponse body returned will be `MyBodyType`.
     None,
+    /// Yaml serializer, the reponse body returned will be `YamlResponse<MyBodyType>` (requires the `yaml` feature).
+    Yaml,
     /// Calls a custom serializer provided in the response declaration parameters.
     Path { path: TypePath },
 }
 
+impl AutorouteResponseSerializer {
+    /// Whether the response body is serialized as json, requiring it to implement `serde::Serialize` and `utoipa::ToSchema`
+    pub(crate) fn is_json(&self) -> bool {
+        match self {
+            Self::Default => cfg!(feature = "default_serializer_json"),
+            Self::Path { path } => path.path.segments.last().is_some_and(|segment| segment.ident == "Json"),
+            Self::None | Self::Yaml => false,
+        }
+    }
+}
+
 impl std::fmt::Debug for AutorouteResponseSerializer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Default => write!(f, "Default"),
             Self::None => write!(f, "None"),
+            Self::Yaml => write!(f, "Yaml"),
             Self::Path { path } => f
                 .debug_struct("Path")
                 .field("path", &quote! {#path}.to_string())
@@ -203,12 +306,21 @@ impl Parse for AutorouteResponseSerializer {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if parse_named_ident(input, "NONE").is_ok() {
             Ok(Self::None)
+        } else if let Ok(ident) = parse_named_ident(input, "YAML") {
+            if cfg!(feature = "yaml") {
+                Ok(Self::Yaml)
+            } else {
+                syn_bail!(
+                    ident.span(),
+                    "the `yaml` feature must be enabled to use the `YAML` serializer"
+                )
+            }
         } else if let Ok(path) = input.parse() {
             Ok(Self::Path { path })
         } else {
             syn_bail!(
                 input.span(),
-                "serializer should be either `None` or a path to a serializing type (like `axum::Json`), function or closure"
+                "serializer should be either `NONE`, `YAML` or a path to a serializing type (like `axum::Json`), function or closure"
             )
         }
     }
@@ -220,6 +332,8 @@ impl Parse for AutorouteResponseSerializer {
 pub(crate) struct AutorouteResponseHeader {
     /// The header name.
     pub(crate) header_name: SpannedValue<HeaderName>,
+    /// The optional type of the header value (`String` in the openapi documentation if not provided).
+    pub(crate) schema: Option<Type>,
     /// The associated description.
     pub(crate) description: Option<LitStr>,
     pub(crate) span: Span,
@@ -229,33 +343,75 @@ impl std::fmt::Debug for AutorouteResponseHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AutorouteResponseHeader")
             .field("header_type", &self.header_name)
+            .field(
+                "schema",
+                &self.schema.as_ref().map(|schema| quote! {#schema}.to_string()),
+            )
             .field("description", &self.description.as_ref().map(LitStr::value))
             .finish_non_exhaustive()
     }
 }
 
+/// Enum listing the different non-positional parameters of the response headers.
+#[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum AutorouteResponseHeaderKey {
+    Schema,
+    Description,
+}
+
 impl Parse for AutorouteResponseHeader {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key_error = |span| {
+            syn::Error::new(
+                span,
+                format!(
+                    "expected one of: {}",
+                    AutorouteResponseHeaderKey::iter()
+                        .map(|key| key.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        };
+
         let content;
         let parentheses = parenthesized!(content in input);
 
         let header_name = parse_header_name(&content)?;
-        if !content.is_empty() {
-            content.parse::<Token![,]>()?;
-        }
 
+        // optional fields
+        let mut schema = None;
         let mut description = None;
-        if !content.is_empty() {
-            parse_named_ident(&content, "description")?;
-            content.parse::<Token![=]>()?;
-            description = Some(content.parse()?);
-        }
-        if !content.is_empty() {
+        while !content.is_empty() {
+            // allow trailing comma
             content.parse::<Token![,]>()?;
+            if content.is_empty() {
+                break;
+            }
+
+            let ident: Ident = content.parse().map_err(|e| key_error(e.span()))?;
+            let key = AutorouteResponseHeaderKey::from_str(&ident.to_string()).map_err(|_| key_error(ident.span()))?;
+            content.parse::<Token![=]>()?;
+            match key {
+                AutorouteResponseHeaderKey::Schema => {
+                    if schema.is_some() {
+                        syn_bail!(ident.span(), "{} already defined", key.to_string());
+                    }
+                    schema = Some(content.parse()?);
+                }
+                AutorouteResponseHeaderKey::Description => {
+                    if description.is_some() {
+                        syn_bail!(ident.span(), "{} already defined", key.to_string());
+                    }
+                    description = Some(content.parse()?);
+                }
+            }
         }
 
         Ok(Self {
             header_name,
+            schema,
             description,
             span: parentheses.span.join(),
         })
@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use proc_macro2::Span;
+use quote::{ToTokens, quote, quote_spanned};
+use strum::IntoEnumIterator;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token, bracketed, parenthesized};
+
+use crate::syn_bail;
+
+/// Enum listing the pre-configured security schemes which can be referenced without quotes (e.g. `security=[BearerJwt]`).
+/// They must match the names of `axum_autoroute::SecurityPreset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
+enum SecurityPreset {
+    BearerJwt,
+    BasicAuth,
+    ApiKeyHeader,
+    ApiKeyQuery,
+}
+
+/// Struct holding the data for a security requirement declaration in the `autoroute` macro parameters.
+pub(crate) struct AutorouteSecurityRequirement {
+    /// The name of the security scheme, `None` for an empty requirement (making the security optional).
+    pub(crate) name: Option<LitStr>,
+    /// The scopes required for this security scheme.
+    pub(crate) scopes: Vec<LitStr>,
+    pub(crate) span: Span,
+}
+
+impl std::fmt::Debug for AutorouteSecurityRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutorouteSecurityRequirement")
+            .field("name", &self.name.as_ref().map(LitStr::value))
+            .field("scopes", &self.scopes.iter().map(LitStr::value).collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Parse for AutorouteSecurityRequirement {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) {
+            return Self::parse_preset(input);
+        }
+
+        let content;
+        let parentheses = parenthesized!(content in input);
+
+        let mut name = None;
+        let mut scopes = Vec::new();
+        if !content.is_empty() {
+            name = Some(content.parse()?);
+            content.parse::<Token![=]>()?;
+            let scopes_content;
+            bracketed!(scopes_content in content);
+            let punctuated = scopes_content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+            scopes = punctuated.into_iter().collect();
+        }
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            name,
+            scopes,
+            span: parentheses.span.join(),
+        })
+    }
+}
+
+impl AutorouteSecurityRequirement {
+    /// Parse a pre-configured security scheme, with optional scopes (e.g. `BearerJwt` or `BearerJwt = ["read"]`).
+    fn parse_preset(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if SecurityPreset::from_str(&ident.to_string()).is_err() {
+            syn_bail!(
+                ident.span(),
+                "unknown security scheme `{ident}`, expected one of: {} (or a `(\"name\" = [...])` requirement)",
+                SecurityPreset::iter()
+                    .map(|preset| preset.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        let mut scopes = Vec::new();
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let scopes_content;
+            bracketed!(scopes_content in input);
+            let punctuated = scopes_content.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+            scopes = punctuated.into_iter().collect();
+        }
+
+        Ok(Self {
+            name: Some(LitStr::new(&ident.to_string(), ident.span())),
+            scopes,
+            span: ident.span(),
+        })
+    }
+}
+
+impl ToTokens for AutorouteSecurityRequirement {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let scopes = &self.scopes;
+        let name = self.name.as_ref().map(|name| quote! {#name = [#(#scopes),*]});
+        tokens.extend(quote_spanned! {self.span=> (#name)});
+    }
+}
@@ -4,7 +4,7 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{Ident, Type, parse_quote_spanned};
+use syn::{Ident, LitStr, Type, parse_quote_spanned};
 
 use crate::args::AutorouteInput;
 use crate::args::responses::{AutorouteResponse, AutorouteResponseSerializer};
@@ -33,17 +33,30 @@ pub(crate) fn declare_responses_enum(input: &AutorouteInput) -> syn::Result<proc
         let parts_types = resp.parts.clone();
 
         // declaration of the variant for this response
+        // the default and range responses also hold the status code to return
+        let status_type = status_code
+            .is_runtime()
+            .then(|| quote_spanned! {resp.span=> axum::http::status::StatusCode, });
         variant_declarations.push(quote_spanned! {resp.span=>
-            #variant_name( #(#parts_types,)* #body_type )
+            #variant_name( #status_type #(#parts_types,)* #body_type )
         });
 
         // implementation of axum IntoResponse for this response
         variant_into_response_impls.push(response_variant_into_response_impl(input, resp));
 
         // implementation of the custom status trait (e.g. `IntoNotFound`) for this response
-        variant_response_trait_impls.push(response_variant_trait_impl(&enum_name, resp));
+        variant_response_trait_impls.push(response_variant_trait_impl(input, &enum_name, resp));
     }
 
+    let custom_status_traits = declare_custom_status_traits(input);
+
+    let result_from_impl = result_from_impl(input, &enum_name);
+
+    #[cfg(feature = "tracing")]
+    let response_tracing = crate::codegen::tracing::declare_response_tracing(input);
+    #[cfg(not(feature = "tracing"))]
+    let response_tracing = quote! {};
+
     let msg = format!("Enum containing the different response types for the handler {fn_name}  ");
     let vis = input.itemfn.vis.clone();
     Ok(quote_spanned! {input.meta.responses.span()=>
@@ -62,26 +75,61 @@ pub(crate) fn declare_responses_enum(input: &AutorouteInput) -> syn::Result<proc
             }
         }
 
+        #response_tracing
+
+        #custom_status_traits
+
         #(#variant_response_trait_impls)*
+
+        #result_from_impl
     })
 }
 
+/// Implementation of `From<Result<T, E>>` for the responses enum if `ok_status` and `err_status` are used.
+/// The `Ok` and `Err` values are converted using the traits of their associated status code (e.g. `IntoOk` and `IntoBadRequest`).
+fn result_from_impl(input: &AutorouteInput, enum_name: &Ident) -> TokenStream {
+    let (Some((ok_type, err_type)), [ok_resp, err_resp]) = (&input.result_types, input.meta.responses.as_slice())
+    else {
+        return quote! {};
+    };
+    let ok_trait = response_into_status_trait_path(input, ok_resp);
+    let ok_fn = response_into_status_trait_fn_name(ok_resp);
+    let err_trait = response_into_status_trait_path(input, err_resp);
+    let err_fn = response_into_status_trait_fn_name(err_resp);
+    quote_spanned! {input.meta.responses.span()=>
+        impl From<Result<#ok_type, #err_type>> for #enum_name {
+            fn from(result: Result<#ok_type, #err_type>) -> Self {
+                match result {
+                    Ok(value) => #ok_trait::#ok_fn(value),
+                    Err(value) => #err_trait::#err_fn(value),
+                }
+            }
+        }
+    }
+}
+
 /// Name of the responses enum as String
 pub(crate) fn responses_enum_name(input: &AutorouteInput) -> String {
+    if let Some(enum_name) = &input.meta.enum_name {
+        return enum_name.value();
+    }
     let fn_ident = input.fn_ident();
     format!("{}Responses", fn_ident.to_string().to_case(Case::Pascal))
 }
 
 /// Name of the responses enum as Ident
 pub(crate) fn responses_enum_ident(input: &AutorouteInput) -> Ident {
-    let fn_ident = input.fn_ident();
-    Ident::new(&responses_enum_name(input), fn_ident.span())
+    let span = input
+        .meta
+        .enum_name
+        .as_ref()
+        .map_or_else(|| input.fn_ident().span(), LitStr::span);
+    Ident::new(&responses_enum_name(input), span)
 }
 
 /// Name of the variant for a response (e.g. `NotFound`)
-fn response_variant_ident(resp: &AutorouteResponse) -> Ident {
-    let status_name = resp.status_code.to_string().to_case(Case::Pascal);
-    Ident::new(&status_name, resp.span)
+pub(crate) fn response_variant_ident(resp: &AutorouteResponse) -> Ident {
+    Ident::new(&resp.status_code.pascal_name(), resp.span)
 }
 
 /// Name of the variables set when destructuring the variant (first the body and the parts afterward)
@@ -99,52 +147,131 @@ pub(crate) fn response_variant_variables(resp: &AutorouteResponse) -> (Ident, Ve
 /// Name of the associated status code reponse trait (e.g. `IntoNotFound`)
 pub fn response_into_status_trait_name(resp: &AutorouteResponse) -> Ident {
     Ident::new(
-        &format!("Into{}", resp.status_code.to_string().to_case(Case::Pascal)),
+        &format!("Into{}", resp.status_code.pascal_name()),
         resp.status_code.span(),
     )
 }
 
+/// Full path of the associated status code reponse trait (e.g. `axum_autoroute::status_trait::IntoNotFound`).
+/// The traits of non-standard status codes are declared by the macro, in a module next to the responses enum.
+pub fn response_into_status_trait_path(input: &AutorouteInput, resp: &AutorouteResponse) -> TokenStream {
+    let trait_name = response_into_status_trait_name(resp);
+    if resp.status_code.is_custom() {
+        let module = custom_status_traits_module_ident(input);
+        quote_spanned! {resp.status_code.span()=> self::#module::#trait_name}
+    } else {
+        quote_spanned! {resp.status_code.span()=> axum_autoroute::status_trait::#trait_name}
+    }
+}
+
+/// Name of the module holding the traits of the non-standard status codes of a handler
+fn custom_status_traits_module_ident(input: &AutorouteInput) -> Ident {
+    Ident::new(
+        &format!("__{}_status_traits", responses_enum_name(input).to_case(Case::Snake)),
+        input.fn_ident().span(),
+    )
+}
+
+/// Declare the status code response traits of the non-standard status codes (e.g. `IntoStatus420`),
+/// which cannot be provided by `axum_autoroute::status_trait`.
+fn declare_custom_status_traits(input: &AutorouteInput) -> TokenStream {
+    let traits = input
+        .meta
+        .responses
+        .iter()
+        .filter(|resp| resp.status_code.is_custom())
+        .map(|resp| {
+            let trait_name = response_into_status_trait_name(resp);
+            let trait_fn = response_into_status_trait_fn_name(resp);
+            let trait_doc = format!(
+                "Helper trait to return a {} response from an autoroute function.",
+                resp.status_code.as_code()
+            );
+            let fn_doc = format!(
+                "Convert into the enum variant of the autoroute {} response.",
+                resp.status_code.as_code()
+            );
+            quote_spanned! {resp.status_code.span()=>
+                #[doc=#trait_doc]
+                pub trait #trait_name<R>: Sized {
+                    #[doc=#fn_doc]
+                    fn #trait_fn(self) -> R;
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if traits.is_empty() {
+        return quote! {};
+    }
+
+    let module = custom_status_traits_module_ident(input);
+    let vis = input.itemfn.vis.clone();
+    quote! {
+        #[doc(hidden)]
+        #vis mod #module {
+            #(#traits)*
+        }
+    }
+}
+
 /// Name of the associated status code reponse trait function (e.g. `into_not_found`)
 fn response_into_status_trait_fn_name(resp: &AutorouteResponse) -> Ident {
     Ident::new(
-        &format!("into_{}", resp.status_code.to_string().to_case(Case::Snake)),
+        &format!("into_{}", resp.status_code.snake_name()),
         resp.status_code.span(),
     )
 }
 
-fn response_variant_into_response_impl(
-    #[cfg_attr(not(feature = "tracing"), expect(unused))] input: &AutorouteInput,
-    resp: &AutorouteResponse,
-) -> TokenStream {
+fn response_variant_into_response_impl(input: &AutorouteInput, resp: &AutorouteResponse) -> TokenStream {
     let variant_name = response_variant_ident(resp);
-    let status_code_ident = Ident::new(&resp.status_code.to_string(), resp.status_code.span());
     let (body_var, parts_vars) = response_variant_variables(resp);
+    let (status_var, status_expr) = if resp.status_code.is_range() {
+        // the status provided at runtime must belong to the documented range
+        let status_var = Ident::new("status", resp.status_code.span());
+        let predicate = resp
+            .status_code
+            .axum_class_predicate()
+            .expect("no status class for the range");
+        let predicate = Ident::new(predicate, resp.status_code.span());
+        let response = resp.status_code.openapi_key();
+        (
+            Some(quote! {#status_var, }),
+            quote! {axum_autoroute::response::documented_status(#status_var, #status_var.#predicate(), #response)},
+        )
+    } else if resp.status_code.is_default() {
+        // the status provided at runtime must not be documented by another response of the route
+        let status_var = Ident::new("status", resp.status_code.span());
+        let is_documented = default_status_check(input, &status_var);
+        (
+            Some(quote! {#status_var, }),
+            quote! {axum_autoroute::response::documented_status(#status_var, #is_documented, "default")},
+        )
+    } else {
+        (None, resp.status_code.axum_status(resp.status_code.span()))
+    };
 
     // use the provided serializer or Json by default
     let serializer_expr = match &resp.serializer {
-        AutorouteResponseSerializer::Default => {
-            if cfg!(feature = "default_serializer_json") {
-                quote_spanned! {body_var.span()=> { axum::Json(#body_var)} }
-            } else {
-                quote! {#body_var}
-            }
+        AutorouteResponseSerializer::Default if resp.serializer.is_json() => {
+            // spanned on the body type, where a missing `serde::Serialize` implementation is reported
+            quote_spanned! {resp.body.span()=> axum_autoroute::response::serialize_body(#body_var, axum::Json) }
+        }
+        AutorouteResponseSerializer::Default | AutorouteResponseSerializer::None => quote! {#body_var},
+        AutorouteResponseSerializer::Yaml => {
+            quote_spanned! {body_var.span()=> axum_autoroute::response::YamlResponse(#body_var)}
+        }
+        AutorouteResponseSerializer::Path { path } if resp.serializer.is_json() => {
+            quote_spanned! {resp.body.span()=> axum_autoroute::response::serialize_body(#body_var, #path) }
         }
-        AutorouteResponseSerializer::None => quote! {#body_var},
         AutorouteResponseSerializer::Path { path } => {
             quote_spanned! {path.span()=> #path(#body_var)}
         }
     };
 
-    #[cfg(feature = "tracing")]
-    let tracing_stmt = crate::codegen::tracing::output_tracing(input, &body_var, resp);
-    #[cfg(not(feature = "tracing"))]
-    let tracing_stmt = quote! {};
-
     quote_spanned! {resp.span=>
-        Self::#variant_name(#(#parts_vars,)* #body_var) => {
-            #tracing_stmt
+        Self::#variant_name(#status_var #(#parts_vars,)* #body_var) => {
             (
-                axum::http::status::StatusCode::#status_code_ident,
+                #status_expr,
                 #(#parts_vars,)*
                 #serializer_expr,
             ).into_response()
@@ -152,9 +279,36 @@ fn response_variant_into_response_impl(
     }
 }
 
-fn response_variant_trait_impl(enum_name: &Ident, resp: &AutorouteResponse) -> TokenStream {
+/// Check that the status of the `DEFAULT` response is neither one of the status codes
+/// nor in one of the status code ranges declared by the other responses of the route
+fn default_status_check(input: &AutorouteInput, status_var: &Ident) -> TokenStream {
+    let responses = &input.meta.responses;
+    let declared_codes = responses
+        .iter()
+        .filter(|resp| !resp.status_code.is_runtime())
+        .map(|resp| resp.status_code.as_code())
+        .collect::<Vec<_>>();
+    let declared_ranges = responses
+        .iter()
+        .filter(|resp| resp.status_code.is_range())
+        .filter_map(|resp| resp.status_code.axum_class_predicate())
+        .map(|predicate| Ident::new(predicate, status_var.span()));
+
+    let mut checks = Vec::new();
+    if !declared_codes.is_empty() {
+        checks.push(quote! {!matches!(#status_var.as_u16(), #(#declared_codes)|*)});
+    }
+    checks.extend(declared_ranges.map(|predicate| quote! {!#status_var.#predicate()}));
+    if checks.is_empty() {
+        quote! {true}
+    } else {
+        quote! {#(#checks)&&*}
+    }
+}
+
+fn response_variant_trait_impl(input: &AutorouteInput, enum_name: &Ident, resp: &AutorouteResponse) -> TokenStream {
     let variant_name = response_variant_ident(resp);
-    let trait_name = response_into_status_trait_name(resp);
+    let trait_path = response_into_status_trait_path(input, resp);
     let trait_fn = response_into_status_trait_fn_name(resp);
 
     let body_type = resp.body.clone();
@@ -167,23 +321,34 @@ fn response_variant_trait_impl(enum_name: &Ident, resp: &AutorouteResponse) -> T
         Type::Tuple(parse_quote_spanned! {resp.span=> (#(#parts_types),* , #body_type)})
     };
 
+    // the default and range responses also require the status code to return
+    let (status_arg, status_var) = if resp.status_code.is_runtime() {
+        let status_var = Ident::new("status", resp.status_code.span());
+        (
+            Some(quote! {, #status_var: axum::http::status::StatusCode}),
+            Some(quote! {#status_var, }),
+        )
+    } else {
+        (None, None)
+    };
+
     // the implementation of the into_xxx function of the trait
     let variant_impl = if parts_types.is_empty() {
         quote_spanned! {resp.span=>
-            #enum_name::#variant_name(self)
+            #enum_name::#variant_name(#status_var self)
         }
     } else {
         // tuple starting with the parts types and ending with the body type
         let (body_var, parts_vars) = response_variant_variables(resp);
         quote_spanned! {resp.span=>
             let (#(#parts_vars),* , #body_var) = self;
-            #enum_name::#variant_name(#(#parts_vars),* , #body_var)
+            #enum_name::#variant_name(#status_var #(#parts_vars),* , #body_var)
         }
     };
 
     quote_spanned! {resp.span=>
-        impl axum_autoroute::status_trait::#trait_name<#enum_name> for #impl_for {
-            fn #trait_fn(self) -> #enum_name {
+        impl #trait_path<#enum_name> for #impl_for {
+            fn #trait_fn(self #status_arg) -> #enum_name {
                 #variant_impl
             }
         }
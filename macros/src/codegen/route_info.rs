@@ -5,16 +5,18 @@ use syn::Ident;
 use crate::args::AutorouteInput;
 
 pub(crate) fn declare_route_info(input: &AutorouteInput) -> proc_macro2::TokenStream {
-    let method = input.method();
+    // a route handling several methods is described by its first method
+    let method = input.methods()[0];
     let method_ident = Ident::new(&method.to_string(), method.span());
     let method = quote_spanned! {method.span()=> axum::http::Method::#method_ident};
     let path = input.path();
+    let tags = &input.meta.tags;
     let route_info = route_info_ident(input);
     let vis = input.itemfn.vis.clone();
 
     quote_spanned! {path.span()=>
         #[allow(unused)]
-        #vis const #route_info: axum_autoroute::RouteInfo = axum_autoroute::RouteInfo::new(#method, #path);
+        #vis const #route_info: axum_autoroute::RouteInfo = axum_autoroute::RouteInfo::new_with_tags(#method, #path, &[#(#tags),*]);
     }
 }
 
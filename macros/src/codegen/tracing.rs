@@ -5,10 +5,13 @@ use syn::{Ident, Stmt, parse_quote_spanned};
 use crate::AutorouteInput;
 use crate::args::extractors::AutorouteAxumExtractorType;
 use crate::args::responses::AutorouteResponse;
+use crate::codegen::responses::{response_variant_ident, response_variant_variables, responses_enum_ident};
+use crate::utils::trace_level::TraceLevel;
 
-/// Log input parameters of an autoroute function.
+/// Wrap the body of an autoroute handler in a tracing span and log its input and output parameters.
 /// Modifies the function block of the ItemFn contained in AutorouteInput.
-pub fn add_inputs_tracing(input: &mut AutorouteInput) {
+pub fn add_handler_tracing(input: &mut AutorouteInput) {
+    let span = input.fn_ident().span();
     let msg = format!("'{}' triggered", input.fn_ident());
 
     let mut extractor_traces = Vec::new();
@@ -21,43 +24,154 @@ pub fn add_inputs_tracing(input: &mut AutorouteInput) {
             };
 
             let extracted_var = &extractor.extracted_var;
-            extractor_traces.push(quote_spanned! {extractor.full_ty.span()=>
-                tracing::debug!("* {}: {:?}", #ty_str, #extracted_var);
+            let trace_macro = extractor.attr.trace_level.tracing_macro(extractor.full_ty.span());
+            extractor_traces.push(if cfg!(feature = "tracing_json") {
+                quote_spanned! {extractor.full_ty.span()=>
+                    #trace_macro!(extractor = #ty_str, value = ?#extracted_var, "extractor");
+                }
+            } else {
+                quote_spanned! {extractor.full_ty.span()=>
+                    #trace_macro!("* {}: {:?}", #ty_str, #extracted_var);
+                }
             });
         }
     }
 
-    let tracing_stmts: Vec<Stmt> = parse_quote_spanned! {input.fn_ident().span()=>
-        tracing::debug!(#msg);
-        #(#extractor_traces)*
+    // the span carries the handler name, its methods and its path
+    let span_name = input.fn_ident().to_string();
+    let methods = input
+        .methods()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let path = input.path();
+    let enum_ident = responses_enum_ident(input);
+    let trace_macro = TraceLevel::default().tracing_macro(span);
+    let block = &input.itemfn.block;
+
+    let (duration_start, duration_trace) = if input.meta.trace_duration {
+        let duration_trace = if cfg!(feature = "tracing_json") {
+            quote_spanned! {span=> #trace_macro!(duration_ms = __autoroute_start.elapsed().as_millis(), "duration"); }
+        } else {
+            quote_spanned! {span=> #trace_macro!("duration_ms={}", __autoroute_start.elapsed().as_millis()); }
+        };
+        (
+            quote_spanned! {span=> let __autoroute_start = std::time::Instant::now(); },
+            duration_trace,
+        )
+    } else {
+        (quote! {}, quote! {})
     };
 
-    // add the tracing instructions at the start of the function
-    input.itemfn.block.stmts.splice(0..0, tracing_stmts);
+    // the original block is run in a closure (or an async block) so that early returns still go through the output traces
+    let stmts: Vec<Stmt> = if input.itemfn.sig.asyncness.is_some() {
+        parse_quote_spanned! {span=>
+            let __autoroute_span = tracing::info_span!(#span_name, method = #methods, path = #path);
+            tracing::Instrument::instrument(
+                async move {
+                    #trace_macro!(#msg);
+                    #(#extractor_traces)*
+                    #duration_start
+                    let __autoroute_response: #enum_ident = async move #block.await;
+                    __autoroute_response.__autoroute_trace_response();
+                    #duration_trace
+                    __autoroute_response
+                },
+                __autoroute_span,
+            )
+            .await
+        }
+    } else {
+        parse_quote_spanned! {span=>
+            let __autoroute_span = tracing::info_span!(#span_name, method = #methods, path = #path).entered();
+            #trace_macro!(#msg);
+            #(#extractor_traces)*
+            #duration_start
+            let __autoroute_response: #enum_ident = (move || #block)();
+            __autoroute_response.__autoroute_trace_response();
+            #duration_trace
+            __autoroute_response
+        }
+    };
+    input.itemfn.block.stmts = stmts;
+}
+
+/// Declare the function logging the output parameters of an autoroute handler.
+/// It is called inside the handler span, once the response has been built.
+pub fn declare_response_tracing(input: &AutorouteInput) -> proc_macro2::TokenStream {
+    let enum_ident = responses_enum_ident(input);
+    let arms = input.meta.responses.iter().map(|resp| output_tracing(input, resp));
+    quote! {
+        impl #enum_ident {
+            #[doc(hidden)]
+            fn __autoroute_trace_response(&self) {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+    }
 }
 
 /// Log output parameters of an autoroute handler for a given response.
-/// Returns the tracing instructions token stream.
-pub fn output_tracing(
-    input: &AutorouteInput,
-    out_body_var: &Ident,
-    resp: &AutorouteResponse,
-) -> proc_macro2::TokenStream {
-    let msg = format!(
-        "'{}' finished -> {}:{}",
-        input.fn_ident(),
-        resp.status_code.as_code(),
-        resp.status_code
-    );
-
-    let resp_trace = if resp.do_trace {
-        quote_spanned! {input.fn_ident().span()=> tracing::debug!("* Response: {:?}", #out_body_var); }
+/// Returns the match arm tracing this response.
+fn output_tracing(input: &AutorouteInput, resp: &AutorouteResponse) -> proc_macro2::TokenStream {
+    let variant_name = response_variant_ident(resp);
+    let (body_var, _) = response_variant_variables(resp);
+
+    let (status_var, msg) = if cfg!(feature = "tracing_json") {
+        // the status code is a structured field, the status name being deduced from it
+        let msg = format!("'{}' finished", input.fn_ident());
+        if resp.status_code.is_runtime() {
+            let status_var = Ident::new("status", resp.status_code.span());
+            (
+                Some(quote! {#status_var, }),
+                quote! {status = #status_var.as_u16(), #msg},
+            )
+        } else {
+            let code = resp.status_code.as_code();
+            (None, quote! {status = #code, #msg})
+        }
+    } else if resp.status_code.is_runtime() {
+        // the status code of the default and range responses is only known at runtime
+        let status_var = Ident::new("status", resp.status_code.span());
+        let msg = format!("'{}' finished -> {{}}:{}", input.fn_ident(), resp.status_code);
+        (Some(quote! {#status_var, }), quote! {#msg, #status_var.as_u16()})
+    } else if resp.status_code.is_custom() {
+        let msg = format!(
+            "'{}' finished -> {}:CUSTOM",
+            input.fn_ident(),
+            resp.status_code.as_code()
+        );
+        (None, quote! {#msg})
     } else {
-        quote! {}
+        let msg = format!(
+            "'{}' finished -> {}:{}",
+            input.fn_ident(),
+            resp.status_code.as_code(),
+            resp.status_code
+        );
+        (None, quote! {#msg})
     };
 
-    quote_spanned! {input.fn_ident().span()=>
-        tracing::debug!(#msg);
-        #resp_trace
+    let (body_pat, resp_trace) = if resp.do_trace {
+        let trace_macro = resp.trace_level.tracing_macro(input.fn_ident().span());
+        let resp_trace = if cfg!(feature = "tracing_json") {
+            quote_spanned! {input.fn_ident().span()=> #trace_macro!(response = ?#body_var, "response"); }
+        } else {
+            quote_spanned! {input.fn_ident().span()=> #trace_macro!("* Response: {:?}", #body_var); }
+        };
+        (quote! {#body_var}, resp_trace)
+    } else {
+        (quote! {_}, quote! {})
+    };
+
+    let trace_macro = TraceLevel::default().tracing_macro(input.fn_ident().span());
+    quote_spanned! {resp.span=>
+        Self::#variant_name(#status_var .., #body_pat) => {
+            #trace_macro!(#msg);
+            #resp_trace
+        }
     }
 }
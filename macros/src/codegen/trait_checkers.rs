@@ -1,10 +1,12 @@
 use convert_case::{Case, Casing};
 use quote::{quote, quote_spanned};
-use syn::Ident;
+use syn::spanned::Spanned;
+use syn::{Ident, Type, TypePath, parse_quote_spanned};
 
 use crate::args::AutorouteInput;
+use crate::args::responses::AutorouteResponse;
 
-/// Create declaration of dummy structs that will check that some types implement a specific trait.
+/// Create declaration of dummy structs (and type aliases) that will check that some types implement a specific trait.
 /// As the code is generated near the route handler declaration, the creation of a new struct is needed as we have no guarantee that the target type is declared in the handler's crate.
 pub(crate) fn declare_trait_checkers(input: &AutorouteInput) -> proc_macro2::TokenStream {
     let mut trait_checkers = Vec::new();
@@ -36,5 +38,127 @@ pub(crate) fn declare_trait_checkers(input: &AutorouteInput) -> proc_macro2::Tok
         trait_checkers.push(trait_checker);
     }
 
+    // the types documented by utoipa in the bodies of the responses serialized as json are checked through an alias
+    // projecting them on `axum_autoroute::response::CheckedSchema`, which requires them to implement ToSchema:
+    // a missing implementation is reported on the body type where utoipa uses the alias, instead of being reported
+    // both by a checker struct and by the code generated by utoipa
+    // (Serialize is checked by `axum_autoroute::response::serialize_body` when building the response)
+    for (i, resp) in input.meta.responses.iter().enumerate() {
+        if !resp.serializer.is_json() {
+            continue;
+        }
+        for (j, ty) in schema_types(&resp.body).into_iter().enumerate() {
+            if !is_schema_object(ty) {
+                continue;
+            }
+            let alias = checked_schema_ident(input, i, j, ty);
+            trait_checkers.push(quote_spanned! {ty.span()=>
+                #[allow(dead_code)]
+                type #alias = <#ty as axum_autoroute::response::CheckedSchema>::Schema;
+            });
+        }
+    }
+
     quote! {#(#trait_checkers)*}
 }
+
+/// The body type of the response documented by utoipa, in which the types implementing `utoipa::ToSchema`
+/// are replaced by their checked alias declared by `declare_trait_checkers` (for the responses serialized as json).
+pub(crate) fn checked_schema_body(input: &AutorouteInput, resp_idx: usize, resp: &AutorouteResponse) -> Type {
+    let mut body = resp.body.clone();
+    if !resp.serializer.is_json() {
+        return body;
+    }
+    for (j, ty) in schema_types_mut(&mut body).into_iter().enumerate() {
+        if is_schema_object(ty) {
+            let alias = checked_schema_ident(input, resp_idx, j, ty);
+            *ty = parse_quote_spanned! {ty.span()=> #alias};
+        }
+    }
+    body
+}
+
+/// Name of the alias checking that the `j`th type documented in the body of the `i`th response implements `utoipa::ToSchema`.
+fn checked_schema_ident(input: &AutorouteInput, i: usize, j: usize, ty: &Type) -> Ident {
+    Ident::new(
+        &format!(
+            "_{}CheckedSchema{i}_{j}",
+            input.fn_ident().to_string().to_case(Case::Pascal)
+        ),
+        ty.span(),
+    )
+}
+
+/// Whether utoipa documents the type through its `utoipa::ToSchema` implementation,
+/// the types with generic arguments and the ones known by utoipa (e.g. `String` or `i32`) being handled from their name.
+fn is_schema_object(ty: &Type) -> bool {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return false;
+    };
+    path.segments.last().is_some_and(|segment| {
+        segment.arguments.is_empty() && !UTOIPA_KNOWN_TYPES.contains(&segment.ident.to_string().as_str())
+    })
+}
+
+/// Names of the types documented by utoipa without calling their `utoipa::ToSchema` implementation
+/// (including the ones of its optional features, e.g. `Uuid`).
+const UTOIPA_KNOWN_TYPES: &[&str] = &[
+    "String",
+    "str",
+    "char",
+    "bool",
+    "usize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "isize",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "f32",
+    "f64",
+    "Value",
+    "DateTime",
+    "Date",
+    "NaiveDate",
+    "NaiveTime",
+    "NaiveDateTime",
+    "Duration",
+    "Decimal",
+    "PathBuf",
+    "Uuid",
+    "Ulid",
+    "Url",
+    "PrimitiveDateTime",
+    "OffsetDateTime",
+    "Zoned",
+];
+
+/// The types which must implement `utoipa::ToSchema` for a response body to be documented,
+/// utoipa handling itself the tuples, arrays and slices (e.g. `(A, B)` requires `A` and `B` to implement it).
+fn schema_types(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter().flat_map(schema_types).collect(),
+        Type::Paren(paren) => schema_types(&paren.elem),
+        Type::Group(group) => schema_types(&group.elem),
+        Type::Array(array) => schema_types(&array.elem),
+        Type::Slice(slice) => schema_types(&slice.elem),
+        _ => vec![ty],
+    }
+}
+
+/// Same as `schema_types`, to replace the types in the body.
+fn schema_types_mut(ty: &mut Type) -> Vec<&mut Type> {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter_mut().flat_map(schema_types_mut).collect(),
+        Type::Paren(paren) => schema_types_mut(&mut paren.elem),
+        Type::Group(group) => schema_types_mut(&mut group.elem),
+        Type::Array(array) => schema_types_mut(&mut array.elem),
+        Type::Slice(slice) => schema_types_mut(&mut slice.elem),
+        _ => vec![ty],
+    }
+}
@@ -3,17 +3,22 @@ use std::ops::Deref;
 use quote::quote_spanned;
 use syn::{Stmt, parse_quote_spanned};
 
-use crate::{AutorouteInput, codegen::responses::response_into_status_trait_name};
+use crate::{AutorouteInput, codegen::responses::response_into_status_trait_path};
 
 
 /// Add use of Into... traits at the beginning of each autoroute handler
+/// (not needed if the function returns a `Result` which is converted afterward).
 pub fn add_use_traits(input: &mut AutorouteInput) {
+    if input.result_types.is_some() {
+        return;
+    }
+
     let mut use_traits = Vec::new();
     for response in input.meta.responses.deref() {
 
-        let trait_name = response_into_status_trait_name(response);
+        let trait_path = response_into_status_trait_path(input, response);
         use_traits.push(quote_spanned! {response.status_code.span()=>
-            use axum_autoroute::status_trait::#trait_name;
+            use #trait_path;
         });
     }
 
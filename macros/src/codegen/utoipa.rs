@@ -1,13 +1,27 @@
+use mime::Mime;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::Ident;
 use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, Ident, Lit, Meta, Type, TypeTuple};
 
+use crate::args::extractors::AutorouteAxumExtractorType;
+use crate::args::responses::{AutorouteResponse, AutorouteResponseSerializer};
+use crate::codegen::trait_checkers::checked_schema_body;
+use crate::utils::http::HttpStatusCode;
+use crate::utils::mime::KnownMimes;
+use crate::utils::spanned::SpannedValue;
 use crate::{AutorouteInput, syn_bail};
 
 pub(crate) fn declare_utoipa_path_meta(input: &AutorouteInput) -> syn::Result<proc_macro2::TokenStream> {
-    let method = input.method();
-    let method_lower = Ident::new(&method.to_string().to_lowercase(), method.span());
+    let methods_lower = input
+        .methods()
+        .iter()
+        .map(|method| Ident::new(&method.to_string().to_lowercase(), method.span()));
+    let method = if input.methods().len() == 1 {
+        quote! {#(#methods_lower)*}
+    } else {
+        quote! {method(#(#methods_lower),*)}
+    };
     let path = input.path().value();
 
     let tags = if input.meta.tags.is_empty() {
@@ -17,13 +31,33 @@ pub(crate) fn declare_utoipa_path_meta(input: &AutorouteInput) -> syn::Result<pr
         Some(quote! {tags = [ #(#tags),* ], })
     };
 
+    let security = input
+        .meta
+        .security
+        .as_ref()
+        .map(|security| quote! {security(#(#security),*), });
+    let summary = input.meta.summary.as_ref().map(|summary| quote! {summary = #summary, });
+    let description = doc_description(input).map(|description| quote! {description = #description, });
+    let operation_id = input
+        .meta
+        .operation_id
+        .as_ref()
+        .map(|operation_id| quote! {operation_id = #operation_id, });
+
+    check_params_description(input)?;
     let mut request_body = None;
     let mut params = Vec::new();
     for extractor in &*input.axum_extractors {
         let extracted_ty = extractor.extracted_ty.clone();
         if extractor.is_parts_extractor() {
             if extractor.to_add_in_params() {
-                params.push(extracted_ty);
+                if let (AutorouteAxumExtractorType::PathParam, Type::Tuple(tuple)) =
+                    (&*extractor.extractor_ty, &extracted_ty)
+                {
+                    params.extend(tuple_path_params(input, tuple)?);
+                } else {
+                    params.push(quote! {#extracted_ty});
+                }
             }
         } else {
             let content_types = extractor.content_types()?;
@@ -37,56 +71,538 @@ pub(crate) fn declare_utoipa_path_meta(input: &AutorouteInput) -> syn::Result<pr
         }
     }
 
-    let mut responses = Vec::new();
-    for resp in &*input.meta.responses {
-        let status_code = resp.status_code;
-        let status_code_ident = Ident::new(&status_code.to_string(), resp.status_code.span());
-        let body_type = resp.body.clone();
-        let description = resp
-            .description
-            .clone()
-            .map(|desc| quote_spanned! {desc.span()=> description=#desc, });
-
-        let content_type = resp.content_type.clone().map(|ct| {
-            let ct_str = ct.to_string();
-            quote_spanned! {ct.span()=> content_type=#ct_str, }
-        });
+    let responses = declare_responses_meta(input);
 
-        let headers = if resp.headers.is_empty() {
-            None
-        } else {
-            let headers = resp.headers.iter().map(|header| {
-                let header_name = header.header_name.as_str();
-                let description = header
-                    .description
-                    .as_ref()
-                    .map(|desc| quote_spanned! {desc.span()=> description=#desc});
-                quote_spanned! {header.span=> (#header_name, #description)}
-            });
-            Some(quote! {headers(#(#headers),*)})
-        };
+    // if the operation must be modified, the `utoipa::Path` trait is implemented on an inner struct
+    // and the usual `__path_xxx` struct is declared by `declare_utoipa_path_impl`
+    let impl_for = if operation_modifiers(input).is_empty() {
+        None
+    } else {
+        let inner_path_struct = inner_path_struct_ident(input);
+        Some(quote! {impl_for = #inner_path_struct, })
+    };
 
-        responses.push(quote_spanned! {resp.span=> (
-            status=#status_code_ident,
-            body=#body_type,
-            #content_type
-            #description
-            #headers
-        )});
-    }
+    // utoipa detects deprecated routes from the `#[deprecated]` attribute of the function
+    let deprecated = input.meta.deprecated.then(|| quote! {#[deprecated]});
 
     Ok(quote! {
         #[utoipa::path(
-            #method_lower,
+            #method,
             path = #path,
+            #impl_for
             #tags
+            #security
+            #summary
+            #description
+            #operation_id
             #request_body
             responses(#(#responses),*),
             params(#(#params),*),
         )]
+        #deprecated
+    })
+}
+
+/// Declare the struct implementing `utoipa::Path` for the handler if some operation fields are not supported by the `utoipa::path` macro.
+/// It wraps the `utoipa::Path` implementation generated by utoipa and modifies its operation.
+pub(crate) fn declare_utoipa_path_impl(input: &AutorouteInput) -> proc_macro2::TokenStream {
+    let modifiers = operation_modifiers(input);
+    if modifiers.is_empty() {
+        return quote! {};
+    }
+
+    let path_struct = Ident::new(&format!("__path_{}", input.fn_ident()), input.fn_ident().span());
+    let inner_path_struct = inner_path_struct_ident(input);
+    let vis = input.itemfn.vis.clone();
+    let callbacks = declare_utoipa_callbacks(input);
+    let callback_path_structs = (0..input.meta.callbacks.len()).map(|idx| callback_path_struct_ident(input, idx));
+    quote! {
+        #callbacks
+
+        #[allow(non_camel_case_types)]
+        #[doc(hidden)]
+        #[derive(Clone)]
+        #vis struct #inner_path_struct;
+
+        #[allow(non_camel_case_types)]
+        #[doc(hidden)]
+        #[derive(Clone)]
+        #vis struct #path_struct;
+
+        impl<'t> utoipa::__dev::Tags<'t> for #path_struct {
+            fn tags() -> Vec<&'t str> {
+                <#inner_path_struct as utoipa::__dev::Tags>::tags()
+            }
+        }
+
+        impl utoipa::Path for #path_struct {
+            fn path() -> String {
+                <#inner_path_struct as utoipa::Path>::path()
+            }
+
+            fn methods() -> Vec<utoipa::openapi::path::HttpMethod> {
+                <#inner_path_struct as utoipa::Path>::methods()
+            }
+
+            fn operation() -> utoipa::openapi::path::Operation {
+                let mut operation = <#inner_path_struct as utoipa::Path>::operation();
+                #(#modifiers)*
+                operation
+            }
+        }
+
+        impl utoipa::__dev::SchemaReferences for #path_struct {
+            fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
+                <#inner_path_struct as utoipa::__dev::SchemaReferences>::schemas(schemas);
+                #(<#callback_path_structs as utoipa::__dev::SchemaReferences>::schemas(schemas);)*
+            }
+        }
+    }
+}
+
+/// Description of the route built from the doc attributes of the function, if some of them are not string literals
+/// (e.g. `#[doc = include_str!("my_handler.md")]`).
+/// utoipa only reads the literal doc comments, so the description is given explicitly to the `utoipa::path` macro.
+/// As utoipa does, the first paragraph of the literal doc comments is left out as it is used as the summary.
+fn doc_description(input: &AutorouteInput) -> Option<TokenStream> {
+    let docs: Vec<&Expr> = input
+        .itemfn
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => Some(&name_value.value),
+            _ => None,
+        })
+        .collect();
+    if docs.iter().all(|doc| doc_literal(doc).is_some()) {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+    let mut in_summary = true;
+    for doc in docs {
+        if let Some(line) = doc_literal(doc) {
+            let line = line.strip_prefix(' ').unwrap_or(&line).trim_end().to_string();
+            if in_summary && !line.is_empty() {
+                continue;
+            }
+            in_summary = false;
+            parts.push(quote! {#line});
+        } else {
+            in_summary = false;
+            parts.push(quote_spanned! {doc.span()=> #doc});
+        }
+    }
+    Some(quote! {[#(#parts),*].join("\n").trim().to_string()})
+}
+
+/// Value of a doc attribute if it is a string literal (e.g. generated from a `///` comment)
+fn doc_literal(doc: &Expr) -> Option<String> {
+    match doc {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// Declare a hidden function for each callback of the route, annotated with the `utoipa::path` macro
+/// so that utoipa generates the operation of the callback request.
+fn declare_utoipa_callbacks(input: &AutorouteInput) -> TokenStream {
+    let callbacks = input.meta.callbacks.iter().enumerate().map(|(idx, callback)| {
+        let method = Ident::new(&callback.method.to_string().to_lowercase(), callback.method.span());
+        let url = &callback.url;
+        let request_body = callback.body.as_ref().map(|body| quote! {request_body = #body, });
+        let responses = callback
+            .responses
+            .iter()
+            .map(|resp| declare_response_meta(resp, &resp.body));
+        let callback_fn = callback_fn_ident(input, idx);
+        quote_spanned! {callback.span=>
+            #[utoipa::path(
+                #method,
+                path = #url,
+                #request_body
+                responses(#(#responses),*),
+            )]
+            #[allow(dead_code, non_snake_case)]
+            #[doc(hidden)]
+            fn #callback_fn() {}
+        }
+    });
+    quote! {#(#callbacks)*}
+}
+
+/// Name of the hidden function declared for the callback at index `idx`
+fn callback_fn_ident(input: &AutorouteInput, idx: usize) -> Ident {
+    Ident::new(
+        &format!("__autoroute_callback_{}_{idx}", input.fn_ident()),
+        input.fn_ident().span(),
+    )
+}
+
+/// Name of the struct on which utoipa implements `utoipa::Path` for the callback at index `idx`
+fn callback_path_struct_ident(input: &AutorouteInput, idx: usize) -> Ident {
+    Ident::new(
+        &format!("__path_{}", callback_fn_ident(input, idx)),
+        input.fn_ident().span(),
+    )
+}
+
+/// Name of the struct on which utoipa implements `utoipa::Path` when the operation must be modified
+fn inner_path_struct_ident(input: &AutorouteInput) -> Ident {
+    Ident::new(
+        &format!("__autoroute_path_{}", input.fn_ident()),
+        input.fn_ident().span(),
+    )
+}
+
+/// Statements modifying the `operation` generated by utoipa, for fields not supported by the `utoipa::path` macro
+fn operation_modifiers(input: &AutorouteInput) -> Vec<TokenStream> {
+    let mut modifiers = Vec::new();
+    if let Some(external_docs) = &input.meta.external_docs {
+        modifiers.push(quote! {operation.external_docs = Some(#external_docs);});
+    }
+    modifiers.extend(query_style_modifier(input));
+    modifiers.extend(params_description_modifiers(input));
+    modifiers.extend(callbacks_modifier(input));
+    #[cfg(feature = "extractor_attr")]
+    modifiers.extend(schema_override_modifiers(input));
+    modifiers.extend(examples_modifiers(input));
+    modifiers
+}
+
+/// Document the `Vec<T>` query parameters as repeated keys (e.g. `?ids=1&ids=2`), unless a style is already set.
+fn query_style_modifier(input: &AutorouteInput) -> Option<TokenStream> {
+    let has_query = input
+        .axum_extractors
+        .iter()
+        .any(|extractor| matches!(*extractor.extractor_ty, AutorouteAxumExtractorType::QueryParam));
+    has_query.then(|| {
+        quote! {
+            for parameter in operation.parameters.iter_mut().flatten() {
+                if parameter.parameter_in == utoipa::openapi::path::ParameterIn::Query
+                    && parameter.style.is_none()
+                    && matches!(parameter.schema, Some(utoipa::openapi::RefOr::T(utoipa::openapi::schema::Schema::Array(_))))
+                {
+                    parameter.style = Some(utoipa::openapi::path::ParameterStyle::Form);
+                    parameter.explode = Some(true);
+                }
+            }
+        }
+    })
+}
+
+/// Set the descriptions of the parameters listed in `params_description`.
+fn params_description_modifiers(input: &AutorouteInput) -> Vec<TokenStream> {
+    input
+        .meta
+        .params_description
+        .iter()
+        .map(|(name, description)| {
+            // the names of the parameters declared by `utoipa::IntoParams` cannot be checked at compile time,
+            // an unknown name is skipped instead of failing when the openapi documentation is generated
+            quote_spanned! {name.span()=>
+                if let Some(parameter) = operation
+                    .parameters
+                    .iter_mut()
+                    .flatten()
+                    .find(|parameter| parameter.name == #name)
+                {
+                    parameter.description = Some(#description.to_string());
+                }
+            }
+        })
+        .collect()
+}
+
+/// Add the callbacks of the operation.
+fn callbacks_modifier(input: &AutorouteInput) -> Option<TokenStream> {
+    if input.meta.callbacks.is_empty() {
+        return None;
+    }
+    let callbacks = input.meta.callbacks.iter().enumerate().map(|(idx, callback)| {
+        let callback_path_struct = callback_path_struct_ident(input, idx);
+        let name = &callback.name;
+        let url = &callback.url;
+        quote_spanned! {callback.span=>
+            let mut callback_operation = <#callback_path_struct as utoipa::Path>::operation();
+            callback_operation.operation_id = None;
+            let callback_method = <#callback_path_struct as utoipa::Path>::methods().remove(0);
+            callbacks.insert(
+                #name.to_string(),
+                utoipa::openapi::path::PathsBuilder::new()
+                    .path(#url, utoipa::openapi::path::PathItem::new(callback_method, callback_operation))
+                    .build(),
+            );
+        }
+    });
+    // utoipa types the callbacks of an operation as a string, so they are added with the extensions
+    // which are flattened in the serialized operation.
+    // This is a hack: the `callbacks` key has no `x-` prefix, and the extensions are not meant to hold standard
+    // fields of the specification (see the `callbacks.openapi.json` reference of the example crate).
+    Some(quote! {
+        let mut callbacks = std::collections::BTreeMap::new();
+        #(#callbacks)*
+        let callbacks = axum_autoroute::__private::serde_json::to_value(callbacks).expect("failed to serialize the callbacks");
+        operation
+            .extensions
+            .get_or_insert_with(Default::default)
+            .merge(utoipa::openapi::extensions::Extensions::from_iter([("callbacks", callbacks)]));
     })
 }
 
+/// Override the schema of the extractors having a `schema` set in their attribute.
+#[cfg(feature = "extractor_attr")]
+fn schema_override_modifiers(input: &AutorouteInput) -> Vec<TokenStream> {
+    let mut modifiers = Vec::new();
+    for extractor in &*input.axum_extractors {
+        let Some(schema) = extractor.attr.schema_override() else {
+            continue;
+        };
+        let extracted_ty = &extractor.extracted_ty;
+        if extractor.is_parts_extractor() {
+            // override the schema of the parameters declared by the extracted type
+            modifiers.push(quote_spanned! {extracted_ty.span()=>
+                let names: Vec<String> = <#extracted_ty as utoipa::IntoParams>::into_params(|| None)
+                    .into_iter()
+                    .map(|parameter| parameter.name)
+                    .collect();
+                for parameter in operation.parameters.iter_mut().flatten() {
+                    if names.contains(&parameter.name) {
+                        parameter.schema = Some(#schema);
+                    }
+                }
+            });
+        } else {
+            modifiers.push(quote_spanned! {extracted_ty.span()=>
+                if let Some(request_body) = operation.request_body.as_mut() {
+                    for content in request_body.content.values_mut() {
+                        content.schema = Some(#schema);
+                    }
+                }
+            });
+        }
+    }
+    modifiers
+}
+
+/// Set the examples of the request body and of the responses.
+fn examples_modifiers(input: &AutorouteInput) -> Vec<TokenStream> {
+    let mut modifiers = Vec::new();
+    for extractor in &*input.axum_extractors {
+        for (name, example) in &extractor.attr.examples {
+            let msg = format!("failed to serialize request body example `{}`", name.value());
+            modifiers.push(quote_spanned! {example.span()=>
+                if let Some(request_body) = operation.request_body.as_mut() {
+                    let example = utoipa::openapi::example::ExampleBuilder::new()
+                        .value(Some(axum_autoroute::__private::serde_json::to_value(#example).expect(#msg)))
+                        .build();
+                    for content in request_body.content.values_mut() {
+                        content.examples.insert(#name.to_string(), utoipa::openapi::RefOr::T(example.clone()));
+                    }
+                }
+            });
+        }
+    }
+    for resp in &*input.meta.responses {
+        if let Some(example) = &resp.example {
+            // examples are set on the openapi content, utoipa only supports `json!(...)` examples
+            let status_key = resp.status_code.openapi_key();
+            modifiers.push(quote_spanned! {example.span()=>
+                if let Some(utoipa::openapi::RefOr::T(response)) = operation.responses.responses.get_mut(#status_key) {
+                    let example = axum_autoroute::__private::serde_json::to_value(#example).expect("failed to serialize response example");
+                    for content in response.content.values_mut() {
+                        content.example = Some(example.clone());
+                    }
+                }
+            });
+        }
+        for (name, example) in &resp.examples {
+            let status_key = resp.status_code.openapi_key();
+            let msg = format!("failed to serialize response example `{}`", name.value());
+            modifiers.push(quote_spanned! {example.span()=>
+                if let Some(utoipa::openapi::RefOr::T(response)) = operation.responses.responses.get_mut(#status_key) {
+                    let example = utoipa::openapi::example::ExampleBuilder::new()
+                        .value(Some(axum_autoroute::__private::serde_json::to_value(#example).expect(#msg)))
+                        .build();
+                    for content in response.content.values_mut() {
+                        content.examples.insert(#name.to_string(), utoipa::openapi::RefOr::T(example.clone()));
+                    }
+                }
+            });
+        }
+    }
+    modifiers
+}
+
+fn declare_responses_meta(input: &AutorouteInput) -> Vec<TokenStream> {
+    let mut responses: Vec<TokenStream> = input
+        .meta
+        .responses
+        .iter()
+        .enumerate()
+        .map(|(i, resp)| declare_response_meta(resp, &checked_schema_body(input, i, resp)))
+        .collect();
+
+    // documents the responses returned when an extractor fails, if its status code is not already documented
+    // (either explicitly set in the extractor attribute, or the default one of known extractors if `auto_document_rejections` is enabled)
+    let mut documented_codes: Vec<HttpStatusCode> = input.meta.responses.iter().map(|resp| *resp.status_code).collect();
+    for extractor in &input.axum_extractors {
+        let (status_code, body_type, description) = if let Some(status_code) = extractor.attr.rejection_status {
+            let body_type = extractor
+                .attr
+                .rejection_body
+                .as_ref()
+                .map_or_else(|| quote! {String}, |body| quote! {#body});
+            let ty_str = if let AutorouteAxumExtractorType::Unknown { ty } = &*extractor.extractor_ty {
+                ty.to_string()
+            } else {
+                extractor.extractor_ty.to_string()
+            };
+            (status_code, body_type, format!("The `{ty_str}` extractor failed"))
+        } else if input.meta.auto_document_rejections
+            && let Some((status_code, description)) = extractor.default_rejection()
+        {
+            (
+                SpannedValue::new(status_code, extractor.full_ty.span()),
+                quote! {String},
+                description.to_string(),
+            )
+        } else {
+            continue;
+        };
+        if documented_codes.contains(&status_code) {
+            continue;
+        }
+        documented_codes.push(*status_code);
+
+        let status_code_ident = status_code.utoipa_status(status_code.span());
+        responses.push(quote_spanned! {status_code.span()=> (
+            status=#status_code_ident,
+            body=#body_type,
+            description=#description,
+        )});
+    }
+
+    responses
+}
+
+/// The utoipa declaration of a response (e.g. `(status=OK, body=MyBody, description="...")`), `body_type` being documented
+fn declare_response_meta(resp: &AutorouteResponse, body_type: &Type) -> TokenStream {
+    let status_code = resp.status_code;
+    let status_code_ident = status_code.utoipa_status(status_code.span());
+    // the canonical reason phrase of the status code (e.g. `Not Found`) is used when no description is given
+    let description = resp.description.clone().map_or_else(
+        || {
+            let reason = status_code.reason_phrase();
+            quote_spanned! {status_code.span()=> description=#reason, }
+        },
+        |desc| quote_spanned! {desc.span()=> description=#desc, },
+    );
+
+    let content_type = match (&resp.content_type, &resp.serializer) {
+        (Some(ct), _) => {
+            let ct_str = ct.to_string();
+            Some(quote_spanned! {ct.span()=> content_type=#ct_str, })
+        }
+        (None, AutorouteResponseSerializer::Yaml) => {
+            let ct_str = Mime::from(KnownMimes::ApplicationYaml).to_string();
+            Some(quote_spanned! {resp.span=> content_type=#ct_str, })
+        }
+        (None, _) => None,
+    };
+
+    let headers = if resp.headers.is_empty() {
+        None
+    } else {
+        let headers = resp.headers.iter().map(|header| {
+            let header_name = header.header_name.as_str();
+            let schema = header
+                .schema
+                .as_ref()
+                .map(|schema| quote_spanned! {schema.span()=> = #schema});
+            let description = header
+                .description
+                .as_ref()
+                .map(|desc| quote_spanned! {desc.span()=> description=#desc});
+            quote_spanned! {header.span=> (#header_name #schema, #description)}
+        });
+        Some(quote! {headers(#(#headers),*)})
+    };
+
+    quote_spanned! {resp.span=> (
+        status=#status_code_ident,
+        body=#body_type,
+        #content_type
+        #description
+        #headers
+    )}
+}
+
+/// Declare a positional path parameter for each element of a tuple extracted with `Path<(T1, T2, ...)>`.
+/// The parameters are named after the path segments of the route, in the same order.
+fn tuple_path_params(input: &AutorouteInput, tuple: &TypeTuple) -> syn::Result<Vec<TokenStream>> {
+    let path = input.path();
+    let names = path_param_names(&path.value());
+    if names.len() != tuple.elems.len() {
+        syn_bail!(
+            tuple.span(),
+            "the path extractor tuple has {} elements but the path `{}` has {} parameters",
+            tuple.elems.len(),
+            path.value(),
+            names.len()
+        );
+    }
+    Ok(names
+        .iter()
+        .zip(&tuple.elems)
+        .map(|(name, ty)| quote_spanned! {ty.span()=> (#name = #ty, Path)})
+        .collect())
+}
+
+/// Check that the parameters described with `params_description` are parameters of the route.
+/// Only the path parameters are known by the macro: the names cannot be checked if the route also has parameters
+/// declared by `utoipa::IntoParams` implementations (e.g. with `Query<T>`), a missing parameter then being ignored
+/// when the openapi documentation is generated.
+fn check_params_description(input: &AutorouteInput) -> syn::Result<()> {
+    let params_extractors = input
+        .axum_extractors
+        .iter()
+        .filter(|extractor| extractor.is_parts_extractor() && extractor.to_add_in_params());
+    let mut known_names = Vec::new();
+    for extractor in params_extractors {
+        if !matches!(*extractor.extractor_ty, AutorouteAxumExtractorType::PathParam) {
+            return Ok(());
+        }
+        known_names = path_param_names(&input.path().value());
+    }
+
+    for (name, _) in &input.meta.params_description {
+        if !known_names.contains(&name.value()) {
+            let expected = if known_names.is_empty() {
+                "the route has no documented parameter".to_string()
+            } else {
+                format!("expected one of: {}", known_names.join(", "))
+            };
+            syn_bail!(
+                name.span(),
+                "parameter `{}` described in params_description is not a parameter of the route ({expected})",
+                name.value()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Names of the parameters of a route path (e.g. `["id", "name"]` for `/users/{id}/{name}`).
+fn path_param_names(path: &str) -> Vec<String> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}'))
+        .map(|(name, _)| name.trim_start_matches('*').to_string())
+        .collect()
+}
+
 fn set_request_body(target: &mut Option<TokenStream>, value: TokenStream) -> syn::Result<()> {
     if target.is_some() {
         syn_bail!(value.span(), "multiple extractors consuming the body are defined");
@@ -3,14 +3,14 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/Evolis-SA/axum-autoroute/refs/heads/main/axum_autoroute.png")]
 
 use args::AutorouteInput;
-use macros_internal::autoroute_path_internal;
+use macros_internal::{autoroute_path_internal, autoroute_test_internal, module_openapi_internal};
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{Ident, Path, PathArguments, PathSegment, parse_macro_input};
 use utils::error::{syn_bail, syn_error};
 
 use crate::codegen::route_info::route_info_name;
-use crate::utils::PathList;
+use crate::utils::{PathList, path_as_str};
 
 #[allow(clippy::doc_markdown)]
 mod args;
@@ -25,19 +25,66 @@ mod utils;
 /// It will be used to define several info about the handler (method, path, allowed responses)
 /// and will also extract others from the function signature.
 ///
+/// The macro must be put on a free function, and not on an associated function of an `impl` block,
+/// as it declares additional items (e.g. the responses enum) next to the function.
+/// Shared data can be accessed through a `State` extractor instead (e.g. `State<Arc<MyController>>`).
+///
 /// See the [axum-autoroute-example](https://github.com/Evolis-SA/axum-autoroute/tree/main/example) crate to get some samples.
 ///
 /// # Autoroute fields
 ///
 /// Required fields:
 /// * The method as an http [`Method`](axum::http::method::Method) constant (e.g. `GET`, `POST`, etc.).
+///   Alternatively `methods=[GET, POST, ...]` can be used for a route handling several methods
+///   (its [`route_info`] will then use the first method of the list).
 ///     * **Must be the first attribute**.
 /// * `path = "..."` The path of the route with its parameters in curly braces (e.g. `"{/my/route/{id}}"`).
 ///     * **Must be the second attribute**.
 /// * `responses=[(...), ...]` A list of responses that can be returned by this route. See below for more information and example.
+///     * Alternatively, `ok_status=...` and `err_status=...` can be used for a function returning a `Result<T, E>` (see below).
 ///
 /// Optional fields:
 /// * `tags=["mytag", ...]` A list of tags for this route. They can be used to group the routes (this is done by swagger-ui for instance).
+/// * `security=[("name" = ["scope", ...]), ...]` A list of security requirements for this route in the openapi specification.
+///     * An empty requirement `()` can be added to make the security optional.
+///     * The security schemes must be declared separately in the openapi components (e.g. using `utoipa::Modify`).
+///     * The pre-configured security schemes `BearerJwt`, `BasicAuth`, `ApiKeyHeader` and `ApiKeyQuery` can be referenced without quotes
+///       (e.g. `security=[BearerJwt, ApiKeyHeader = ["admin"]]`), an unknown name being a compile error.
+///       They must be declared using `AutorouteApiRouter::add_security_preset` (see `SecurityPreset`).
+/// * `summary="..."` A short summary of the route for the openapi specification.
+///   If not set, the first paragraph of the function doc comment is used
+///   (the following paragraphs, separated by a blank line, are used as the description of the route).
+///   The description can also be included from a file with `#[doc = include_str!("my_handler.md")]`.
+/// * `operation_id="..."` The operation id of the route for the openapi specification (the function name by default).
+/// * `deprecated=true|false` Marks the route as deprecated in the openapi specification (`false` by default).
+///   The handler function will also be marked with a `#[deprecated]` attribute.
+/// * `external_docs=("url", "description")` A link to an external documentation of the route for the openapi specification.
+///     * The description is optional.
+/// * `auto_document_rejections=true|false` If enabled, the response returned by axum when a known extractor fails
+///   will be added to the openapi specification with a `String` body, unless its status code is already documented (`false` by default):
+///     * `BAD_REQUEST` for `Path`, `Query`, `TypedMultipart` and `Multipart`.
+///     * `UNPROCESSABLE_ENTITY` for `Json` and `Form`.
+///     * The `rejection_status` of the [extractor attribute](#extractor-attribute) is used instead if set.
+/// * `enum_name="..."` The name of the generated responses enum, which must then be used as the return type of the function.
+///   By default, the name is derived from the function name (e.g. `my_handler` -> `MyHandlerResponses`).
+/// * `params_description=[("name", "description"), ...]` The descriptions of the route parameters in the openapi specification,
+///   overriding the ones of their `utoipa::IntoParams` implementation (e.g. for a type declared in another crate).
+///     * Each parameter must be documented by the route (e.g. extracted with `Path` or `Query`).
+///       The names are checked at compile time, except if the route has parameters declared by a `utoipa::IntoParams` implementation
+///       (e.g. with `Query<T>`): the descriptions of the unknown parameters are then ignored.
+/// * `trace_duration=true|false` Indicates whether the duration of the function should be traced if the `tracing` feature is enabled (`false` by default).
+/// * `ok_status=...` and `err_status=...` The status codes of the responses of a function returning a `Result<T, E>`
+///   (e.g. `ok_status=OK, err_status=BAD_REQUEST`), instead of the responses enum.
+///     * Both are required and cannot be used along with `responses`.
+///     * Two responses are declared, with `T` and `E` as their body types and the default response options (e.g. the default serializer).
+///     * The responses enum is still generated, with an implementation of `From<Result<T, E>>` used to convert the value returned by the function.
+///     * `DEFAULT` and status code ranges are not supported.
+/// * `callbacks=[("name", METHOD, "url", body=..., responses=[(...), ...]), ...]` The callbacks of the route in the openapi specification,
+///   i.e. the out-of-band requests sent by the api (e.g. the notifications sent to a webhook registered by this route).
+///     * The url is usually a runtime expression (e.g. `"{$request.body#/callback_url}"`).
+///     * `body=...` is the optional type of the request body sent by the callback (serialized as json).
+///     * The responses expected from the receiver of the callback are declared as the responses of the route (see below),
+///       examples are not supported.
 ///
 ///
 ///
@@ -48,6 +95,15 @@ mod utils;
 /// Required fields:
 /// * The status code as either a numeric value (e.g. `200`, `404` etc.)
 ///   or an http [`StatusCode`](axum::http::status::StatusCode) constant (e.g. `OK`, `NOT_FOUND`, etc.).
+///     * `DEFAULT` can also be used to declare the openapi catch-all response.
+///       In this case, the status code to return must be provided at runtime (e.g. `body.into_default(StatusCode::CONFLICT)`).
+///       A status code declared by another response of the route (explicitly or through a range) is replaced
+///       by a 500 `INTERNAL_SERVER_ERROR` (and fails a debug assertion).
+///     * `1XX`, `2XX`, `3XX`, `4XX` and `5XX` can also be used to declare an openapi status code range.
+///       As for `DEFAULT`, the status code to return must be provided at runtime (e.g. `body.into_status_4xx(StatusCode::NOT_FOUND)`).
+///       A status code outside of the range is replaced by a 500 `INTERNAL_SERVER_ERROR` (and fails a debug assertion).
+///     * Non-standard numeric status codes (between 100 and 599, e.g. `420`) are also supported,
+///       the response is then returned with `into_status_xxx` (e.g. `body.into_status_420()`).
 ///     * **Must be the first attribute**.
 /// * `body=...` The type returned by this response.
 ///     * **Must be the second attribute**.
@@ -60,17 +116,26 @@ mod utils;
 ///
 /// Optional fields:
 /// * `serializer=...` The serializer to use on the response body type.
-///   Can be `NONE` to not use any serializer, or anything that can be called
-///   with a single element having the type of the response body (a closure, function etc.).
+///   Can be `NONE` to not use any serializer, `YAML` to serialize the body as YAML (requires the `yaml` feature),
+///   or anything that can be called with a single element having the type of the response body (a closure, function etc.).
 ///   Axum [`Json`](axum::extract::Json) by default if `default_serializer_json` is enabled.
+///     * With the `Json` serializer, the response body type must implement `serde::Serialize` and `utoipa::ToSchema` (checked at compile time).
+///     * With `YAML`, the `content_type` of the response defaults to `application/yaml`.
 /// * `description="..."` A description of this reponse to add to the openapi specification.
-/// * `content_type=...` The `content_type` of the response as a string (e.g. `"text/plain"`) or as a [`Mime`](mime::Mime) constant (e.g. `TEXT_PLAIN`)
+///   Defaults to the reason phrase of the status code (e.g. `Not Found`, or `Status 420` for a non-standard status code).
+/// * `content_type=...` The `content_type` of the response as a string (e.g. `"text/plain"`) or as a [`Mime`](mime::Mime) constant (e.g. `TEXT_PLAIN`, `APPLICATION_YAML` and `APPLICATION_CBOR` are also supported)
 /// * `headers=[...]` A set of headers returned by this response that should be documented in the openapi specification.
 ///   Each header is enclosed by braces and can have the following fields:
 ///     * The header name as an http [`HeaderName`](axum::http::header) constant (e.g. `SET_COOKIE`, `CONTENT_ENCODING`, etc.)
 ///         * **Required, must be the first attribute**.
+///     * `schema=...` The optional type of the header value for the openapi specification (e.g. `u32`, `String` by default).
 ///     * `description="..."` An optional description for the openapi specification.
+/// * `example=...` An example of the response body for the openapi specification.
+///   It can be any expression implementing `serde::Serialize` (e.g. a `serde_json::json!(...)` value or a struct instance).
+/// * `examples=[("name", ...), ...]` A list of named examples of the response body for the openapi specification.
+///   Each example value can be any expression implementing `serde::Serialize` (as for `example`).
 /// * `trace=true|false` Indicates whether the response content should be traced or not if the `tracing` feature is enabled (`true` by default).
+/// * `trace_level=trace|debug|info|warn|error` The level of the response content trace (`debug` by default, see the `tracing_level_xxx` features).
 ///
 ///
 ///
@@ -80,14 +145,34 @@ mod utils;
 ///
 /// Here is a list of the currently detected extractors:
 /// * Parts extractors:
-///     * `axum::extract::Path`. Must extract a struct or enum implementing `serde::Deserialize` and `utoipa::IntoParams`.
+///     * `axum::extract::Path`. Must extract a struct or enum implementing `serde::Deserialize` and `utoipa::IntoParams`,
+///       or a tuple (e.g. `Path<(u32, String)>`) whose elements are documented as the path parameters of the route, in the same order.
 ///     * `axum::extract::Query`. Must extract a struct or enum implementing `serde::Deserialize` and `utoipa::IntoParams`.
+///       Fields typed as `Option<T>` are documented as optional query parameters (`required: false`) with a nullable schema
+///       (e.g. `"type": ["string", "null"]`), the value being `None` when the parameter is absent from the request.
+///       Fields typed as `Vec<T>` are documented with `style: form` and `explode: true` (i.e. repeated keys such as `?ids=1&ids=2`),
+///       unless another style is set with `#[param(style = ...)]`. Note that deserializing such repeated keys requires the `axum_extra::extract::Query` extractor.
+///     * `axum::extract::OriginalUri`. Not added to the openapi specification.
+///     * `axum::extract::ConnectInfo`. Must extract a type implementing `Debug` if the `tracing` feature is enabled. Not added to the openapi specification.
+///     * `axum_extra::extract::Host` (requires the `axum-extra` feature). Not added to the openapi specification.
+///     * `axum::extract::Extension`. Must extract a type implementing `Debug` if the `tracing` feature is enabled. Not added to the openapi specification.
+///     * `axum_extra::extract::CookieJar` (requires the `axum-extra` feature). Not added to the openapi specification, as the names of the expected cookies are unknown.
+///     * `axum_extra::extract::SignedCookieJar` and `axum_extra::extract::PrivateCookieJar` (requires the `axum-extra` feature).
+///       Not added to the openapi specification, as the names of the expected cookies are unknown.
+///       As the cookie values are sensitive, they are not traced unless `#[extractor(trace=true)]` is set.
+///     * `axum::extract::RawPathParams`. Not added to the openapi specification, as the path parameters have no schema.
 /// * Body extractors
 ///   (as specified in the [axum extractors documentation](https://docs.rs/axum/latest/axum/extract/index.html#the-order-of-extractors),
 ///   a single body extractor can be present and must be the last one in the function parameters):
 ///     * `axum::extract::Json`. Must extract a struct or enum implementing `serde::Serialize` and `utoipa::ToSchema`.
 ///     * `axum_typed_multipart::TypedMultipart`. Must extract a struct implementing `axum_typed_multipart::TryFromMultipart` and `utoipa::ToSchema`.
+///     * `axum::extract::Multipart`. To process the fields of a multipart body dynamically (documented as raw bytes). Never traced.
+///     * `axum::extract::Form`. Must extract a struct implementing `serde::Deserialize` and `utoipa::ToSchema`.
+///     * `axum::extract::RawForm`. To extract the raw bytes of an url encoded form.
 ///     * `axum::body::Body`. To extract the raw body.
+///     * `axum::body::Bytes`. To extract the whole raw body at once.
+///     * `axum_extra::extract::JsonLines` (requires the `axum-extra` feature). Must extract a struct or enum implementing `serde::Deserialize`
+///       and `utoipa::ToSchema`, documented as the schema of each line of an `application/x-ndjson` body. Never traced.
 ///
 /// If an unknown extractor type is used, it will by default be considered as a parts extractor (see [`FromRequestParts`](axum::extract::FromRequestParts)) and will never be traced.
 /// See the [Extractor attribute](#extractor-attribute) section below for more information on how to provide information about unknown extractors.
@@ -117,25 +202,49 @@ mod utils;
 ///
 /// Available fields:
 /// * `trace=true|false` Indicates whether the extractor content should be traced or not if the `tracing` feature is enabled. By default, tracing is:
-///     * Enabled for known extractors.
+///     * Enabled for known extractors (except for the ones documented as never traced).
 ///     * Disabled for unknown extractors unless they must be added in openapi specification (see below).
+/// * `trace_level=trace|debug|info|warn|error` The level of the extractor content trace (`debug` by default, see the `tracing_level_xxx` features).
+/// * `rejection_status=...` The status code of the response returned when the extraction fails (e.g. `422` or `UNPROCESSABLE_ENTITY`).
+///   If set, this response is added to the openapi specification (unless the status code is already documented by the handler).
+/// * `rejection_body=...` The body type of the rejection response in the openapi specification (`String` by default).
+///     * Requires `rejection_status`.
+/// * `examples=[("name", ...), ...]` A list of named examples of the request body for the openapi specification.
+///   Each example value can be any expression implementing `serde::Serialize` (e.g. a `serde_json::json!(...)` value).
+///     * Only allowed on body extractors.
 ///
-/// Unstable fields (gated by feature `unstable_extractor_attr`):
+/// Fields gated by feature `extractor_attr` (formerly `unstable_extractor_attr`, which is kept as a deprecated alias):
 /// * `into_params=true|false` If true indicates that the extractor should be added in the openapi specification as a parameter (path, query etc.).
 ///     * Incompatible with `content_type`.
 /// * `content_type=...` If set indicates that the associated function input is a body extractor and that it should be included in the openapi specification. It can be a string (e.g. `"text/plain"`) or a [`Mime`](mime::Mime) constant (e.g. `TEXT_PLAIN`).
 ///     * If several `content_type=...` assignments are performed in a single extractor attribute, they will all be added into the openapi specification.
 ///     * Incompatible with `into_params`.
+/// * `schema_type="..."` Overrides the openapi schema type of the extractor (one of `string`, `integer`, `number`, `boolean`, `array`, `object` or `null`),
+///   e.g. for an extracted type which cannot be represented by utoipa.
+///     * Requires `into_params` or `content_type`.
+///     * With `into_params`, the schema of every parameter declared by the `utoipa::IntoParams` implementation of the extracted type is replaced.
+///     * With `content_type`, the schema of the request body is replaced and the extracted type does not need to implement `utoipa::ToSchema`.
+/// * `schema_format="..."` Overrides the openapi schema format of the extractor (e.g. `"uuid"`).
+///     * Requires `schema_type`.
 ///
 ///
 ///
 /// # Tracing
 ///
 /// If the `tracing` feature is enabled, each time an `autoroute` function is called:
+/// * The function body will run inside an `info` span named after the function, with the `method` and `path` of the route as fields.
 /// * There will be a trace when the function starts.
 /// * There will be a trace for each extractor variable detected (using its `Debug` implementation). See above for more info.
 /// * There will be a trace when the function ends.
 /// * The content of the response returned will be traced (using its `Debug` implementation).
+/// * If `trace_duration=true` is set, the duration of the function will be traced (`duration_ms=...`).
+///
+/// These traces are emitted at the `debug` level, unless another default level is selected
+/// with one of the `tracing_level_trace`, `tracing_level_debug` or `tracing_level_info` features.
+///
+/// If the `tracing_json` feature is enabled, the traced values are emitted as structured fields instead of being formatted in the message
+/// (e.g. `extractor="Query" value=...` for the extractors, `status=200` when the function ends, `response=...` and `duration_ms=...`),
+/// so that a JSON subscriber serializes them as object fields.
 #[proc_macro_attribute]
 pub fn autoroute(meta: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     autoroute_path_internal(false, meta, item)
@@ -148,6 +257,52 @@ pub fn autoroute_debug(meta: proc_macro::TokenStream, item: proc_macro::TokenStr
     autoroute_path_internal(true, meta, item)
 }
 
+/// Generate the boilerplate of a handler test: build the router, send a request to it and check the status code of the response.
+/// It must be placed before the test attribute (e.g. `#[tokio::test]`) of an async function.
+///
+/// The function can take a single argument, which receives the response (an [`axum::response::Response`]),
+/// so that its body only contains the assertions on the response content.
+///
+/// Parameters:
+/// * `handler=...` The name of an [`macro@autoroute`] handler, whose `RouteInfo` provides the method and the path of the request.
+/// * `method=GET|POST|...` The method of the request, overriding the one of the handler.
+/// * `path="/..."` The path of the request, overriding the one of the handler (e.g. to provide the values of the path parameters).
+/// * `expected_status=...` The status code expected in the response (`200` by default), as a number (e.g. `404`) or a name (e.g. `NOT_FOUND`).
+/// * `body=...` A value implementing `serde::Serialize` (e.g. `serde_json::json!(...)`) sent as a JSON body.
+/// * `router=...` The expression building the `AutorouteApiRouter` to test (`router()` by default).
+///
+/// Either `handler` or both `method` and `path` must be defined.
+///
+/// ```ignore
+/// #[autoroute_test(handler=get_item, path="/items/3", expected_status=OK)]
+/// #[tokio::test]
+/// async fn get_item_test(response: Response) {
+///     assert_eq!(response_to_str(response).await, "item 3");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn autoroute_test(meta: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    autoroute_test_internal(meta, item)
+}
+
+/// Share some openapi information between all the [`macro@autoroute`] handlers of an inline module
+/// (including its nested modules), to avoid repeating it on every handler.
+///
+/// Parameters:
+/// * `tags=["mytag", ...]` Tags added to every handler of the module, before the `tags` defined by the handler itself.
+///
+/// ```ignore
+/// #[module_openapi(tags=["payments"])]
+/// mod payments {
+///     #[autoroute(GET, path="/payments", responses=[(OK, body=Vec<Payment>)])]
+///     async fn list_payments() -> ListPaymentsResponses { ... }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn module_openapi(meta: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    module_openapi_internal(meta, item)
+}
+
 #[proc_macro]
 /// Returns a `RouteInfo` from the name of an handler.
 pub fn route_info(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -178,12 +333,42 @@ pub fn routes_info(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     quote! { [ #(#calls),* ] }.into()
 }
 
+/// Returns a `&'static [RouteInfo]` from a list of handlers name.
+/// Unlike [`routes_info!`](macro@routes_info), it can directly initialize a `const` lookup table
+/// (e.g. `const ROUTES: &[RouteInfo] = route_registry!(handler_a, handler_b);`) to be used with `RouteInfo::lookup` or `RouteInfo::lookup_matching`.
+/// A handler listed several times is rejected.
+#[proc_macro]
+pub fn route_registry(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let paths: PathList = parse_macro_input!(item);
+    let mut listed = Vec::new();
+    for path in paths.iter() {
+        let name = path_as_str(path);
+        if listed.contains(&name) {
+            return syn_error!(path.span(), "handler `{name}` already listed")
+                .into_compile_error()
+                .into();
+        }
+        listed.push(name);
+    }
+    let calls = paths
+        .list
+        .into_iter()
+        .map(|p| quote_spanned! {p.span()=> axum_autoroute::route_info!(#p)})
+        .collect::<Vec<_>>();
+    quote! { &[ #(#calls),* ] }.into()
+}
+
 /// Returns an [`UtoipaMethodRouter`](utoipa_axum::router::UtoipaMethodRouter) from the name of an handler.
 #[proc_macro]
 pub fn method_router(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let path: Path = parse_macro_input!(item);
     quote_spanned! {path.span()=>
-        utoipa_axum::routes!(#path)
+        {
+            // registering a deprecated handler should not trigger a warning
+            #[allow(deprecated)]
+            let method_router = utoipa_axum::routes!(#path);
+            method_router
+        }
     }
     .into()
 }
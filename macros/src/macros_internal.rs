@@ -1,14 +1,19 @@
-use quote::quote;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{ReturnType, Type, parse_quote_spanned};
+use syn::{Attribute, FnArg, Item, ItemFn, ItemMod, LitStr, Meta, ReturnType, Stmt, Token, Type, parse_quote_spanned};
 
 use crate::args::AutorouteInput;
+use crate::args::autoroute_test::AutorouteTestMeta;
 use crate::args::extractor_attr::ExtractorAttr;
+use crate::args::module_openapi::ModuleOpenapiMeta;
 use crate::codegen::responses::{declare_responses_enum, responses_enum_ident, responses_enum_name};
 use crate::codegen::route_info::declare_route_info;
 use crate::codegen::trait_checkers::declare_trait_checkers;
 use crate::codegen::trait_use::add_use_traits;
-use crate::codegen::utoipa::declare_utoipa_path_meta;
+use crate::codegen::utoipa::{declare_utoipa_path_impl, declare_utoipa_path_meta};
 use crate::syn_error;
 use crate::utils::error::syn_bail;
 use crate::utils::printdbg;
@@ -42,6 +47,9 @@ fn autoroute_path_internal2(
     let utoipa_path_meta = declare_utoipa_path_meta(&input)?;
     printdbg!(debug, "--- utoipa_path_meta ---\n{utoipa_path_meta}");
 
+    let utoipa_path_impl = declare_utoipa_path_impl(&input);
+    printdbg!(debug, "--- utoipa_path_impl ---\n{utoipa_path_impl}");
+
     let trait_checkers = declare_trait_checkers(&input);
     printdbg!(debug, "--- trait_checkers ---\n{trait_checkers}");
 
@@ -52,7 +60,7 @@ fn autoroute_path_internal2(
     ExtractorAttr::remove_extractor_attrs(&mut input);
 
     #[cfg(feature = "tracing")]
-    crate::codegen::tracing::add_inputs_tracing(&mut input);
+    crate::codegen::tracing::add_handler_tracing(&mut input);
 
     add_use_traits(&mut input);
 
@@ -61,6 +69,8 @@ fn autoroute_path_internal2(
         #utoipa_path_meta
         #func
 
+        #utoipa_path_impl
+
         #responses_enum
 
         #trait_checkers
@@ -71,26 +81,243 @@ fn autoroute_path_internal2(
     Ok(quoted)
 }
 
+pub(crate) fn autoroute_test_internal(
+    meta: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match autoroute_test_internal2(meta.into(), item.into()) {
+        Ok(token_stream) => token_stream,
+        Err(compile_err) => {
+            syn_error!(compile_err.span(), "autoroute_test macro failed: {compile_err}").into_compile_error()
+        }
+    }
+    .into()
+}
+
+fn autoroute_test_internal2(
+    meta: proc_macro2::TokenStream,
+    item: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let meta: AutorouteTestMeta = syn::parse2(meta)?;
+    let mut itemfn: ItemFn = syn::parse2(item)?;
+    let span = itemfn.sig.ident.span();
+    if itemfn.sig.asyncness.is_none() {
+        syn_bail!(span, "the test function should be async");
+    }
+
+    // the optional function argument receives the response
+    let response_binding = match itemfn.sig.inputs.len() {
+        0 => quote_spanned! {span=> _},
+        1 => {
+            let Some(FnArg::Typed(arg)) = itemfn.sig.inputs.first() else {
+                syn_bail!(itemfn.sig.inputs.span(), "expected a typed function argument");
+            };
+            let (pat, ty) = (&arg.pat, &arg.ty);
+            quote_spanned! {arg.span()=> #pat: #ty}
+        }
+        _ => syn_bail!(
+            itemfn.sig.inputs.span(),
+            "expected at most one function argument, receiving the response"
+        ),
+    };
+    itemfn.sig.inputs.clear();
+
+    let route_info = meta
+        .handler
+        .as_ref()
+        .map(|handler| quote_spanned! {handler.span()=> axum_autoroute::route_info!(#handler)});
+    let method = match (&meta.method, &route_info) {
+        (Some(method), _) => {
+            let method_ident = syn::Ident::new(&method.to_string(), method.span());
+            quote_spanned! {method.span()=> axum::http::Method::#method_ident}
+        }
+        (None, Some(route_info)) => quote! {#route_info.method().clone()},
+        // checked when parsing the macro arguments
+        (None, None) => unreachable!("no method for the test request"),
+    };
+    let path = match (&meta.path, &route_info) {
+        (Some(path), _) => quote! {#path},
+        (None, Some(route_info)) => quote! {#route_info.path()},
+        (None, None) => unreachable!("no path for the test request"),
+    };
+    let body = if let Some(body) = &meta.body {
+        quote_spanned! {body.span()=>
+            Some(axum_autoroute::__private::serde_json::to_value(#body).expect("failed to serialize the test request body"))
+        }
+    } else {
+        quote! {None}
+    };
+    let router = if let Some(router) = &meta.router {
+        quote! {#router}
+    } else {
+        quote_spanned! {span=> router()}
+    };
+    let expected_status = meta.expected_status.map_or(200, |status| status.as_code());
+
+    let stmts = &itemfn.block.stmts;
+    itemfn.block.stmts = parse_quote_spanned! {span=>
+        let #response_binding =
+            axum_autoroute::testing::send_request(#router, #method, #path, #body, #expected_status).await;
+        #(#stmts)*
+    };
+    Ok(quote! {#itemfn})
+}
+
+pub(crate) fn module_openapi_internal(
+    meta: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match module_openapi_internal2(meta.into(), item.into()) {
+        Ok(token_stream) => token_stream,
+        Err(compile_err) => {
+            syn_error!(compile_err.span(), "module_openapi macro failed: {compile_err}").into_compile_error()
+        }
+    }
+    .into()
+}
+
+fn module_openapi_internal2(
+    meta: proc_macro2::TokenStream,
+    item: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let meta: ModuleOpenapiMeta = syn::parse2(meta)?;
+    let mut itemmod: ItemMod = syn::parse2(item)?;
+    let Some((_, items)) = &mut itemmod.content else {
+        syn_bail!(
+            itemmod.ident.span(),
+            "module_openapi can only be applied to an inline module (`mod my_module {{ ... }}`)"
+        );
+    };
+    add_module_tags(items, &meta.tags);
+    Ok(quote! {#itemmod})
+}
+
+/// Add the tags to the `autoroute` attribute of every handler of the module items (including the nested modules).
+/// A nested module with its own `module_openapi` attribute receives the tags through this attribute instead,
+/// so that the tags of the outer modules come first.
+fn add_module_tags(items: &mut [Item], tags: &[LitStr]) {
+    for item in items {
+        match item {
+            Item::Fn(itemfn) => add_attrs_tags(&mut itemfn.attrs, &["autoroute", "autoroute_debug"], tags),
+            Item::Mod(itemmod) => {
+                if itemmod
+                    .attrs
+                    .iter()
+                    .any(|attr| is_attr_named(attr, &["module_openapi"]))
+                {
+                    add_attrs_tags(&mut itemmod.attrs, &["module_openapi"], tags);
+                } else if let Some((_, items)) = &mut itemmod.content {
+                    add_module_tags(items, tags);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn is_attr_named(attr: &Attribute, names: &[&str]) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| names.iter().any(|name| segment.ident == name))
+}
+
+fn add_attrs_tags(attrs: &mut [Attribute], names: &[&str], tags: &[LitStr]) {
+    for attr in attrs {
+        if !is_attr_named(attr, names) {
+            continue;
+        }
+        if let Meta::List(list) = &mut attr.meta {
+            list.tokens = tokens_with_tags(std::mem::take(&mut list.tokens), tags);
+        }
+    }
+}
+
+/// Prepend the tags to the `tags=[...]` field of the macro arguments, or add this field if it is not defined.
+/// The arguments are left untouched if the field cannot be parsed, so that the error is reported by the macro itself.
+fn tokens_with_tags(tokens: TokenStream, tags: &[LitStr]) -> TokenStream {
+    let mut trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let tags_field = trees.windows(3).position(|window| {
+        matches!(
+            window,
+            [TokenTree::Ident(ident), TokenTree::Punct(punct), TokenTree::Group(group)]
+                if ident == "tags" && punct.as_char() == '=' && group.delimiter() == Delimiter::Bracket
+        )
+    });
+    if let Some(idx) = tags_field {
+        let TokenTree::Group(group) = &trees[idx + 2] else {
+            unreachable!("the tags field is followed by a bracket group");
+        };
+        let Ok(handler_tags) = Punctuated::<LitStr, Token![,]>::parse_terminated.parse2(group.stream()) else {
+            return trees.into_iter().collect();
+        };
+        let mut all_tags = tags.to_vec();
+        for tag in handler_tags {
+            if !all_tags.iter().any(|other| other.value() == tag.value()) {
+                all_tags.push(tag);
+            }
+        }
+        let mut new_group = Group::new(Delimiter::Bracket, quote! {#(#all_tags),*});
+        new_group.set_span(group.span());
+        trees[idx + 2] = TokenTree::Group(new_group);
+        trees.into_iter().collect()
+    } else {
+        let separator = match trees.last() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => None,
+            _ => Some(quote! {,}),
+        };
+        let tokens: TokenStream = trees.into_iter().collect();
+        quote! {#tokens #separator tags=[#(#tags),*]}
+    }
+}
+
 fn set_func_return_type(input: &mut AutorouteInput) -> syn::Result<()> {
-    check_func_return_type(input)?;
     let span = input.itemfn.sig.output.span();
     let ident = responses_enum_ident(input);
+    if input.result_types.is_some() {
+        // the function returns a `Result`, which is converted into the responses enum
+        let result_type = &input.itemfn.sig.output;
+        let result_type = match result_type {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => syn_bail!(span, "expecting a `Result` return type"),
+        };
+        let block = &input.itemfn.block;
+        let stmts: Vec<Stmt> = if input.itemfn.sig.asyncness.is_some() {
+            parse_quote_spanned! {span=>
+                let __autoroute_result: #result_type = async move #block.await;
+                #ident::from(__autoroute_result)
+            }
+        } else {
+            parse_quote_spanned! {span=>
+                let __autoroute_result: #result_type = (move || #block)();
+                #ident::from(__autoroute_result)
+            }
+        };
+        input.itemfn.block.stmts = stmts;
+    } else {
+        check_func_return_type(input)?;
+    }
     input.itemfn.sig.output = parse_quote_spanned! {span=> -> #ident};
     Ok(())
 }
 
 fn check_func_return_type(input: &AutorouteInput) -> syn::Result<()> {
     let expected_name = responses_enum_name(input);
-    let func_return = &input.itemfn.sig.output;
-    let mut err_span = func_return.span();
-    if let ReturnType::Type(_, box_type) = func_return {
-        err_span = box_type.span();
-        if let Type::Path(path) = &**box_type
-            && let Some(ident) = path.path.get_ident()
-            && *ident == expected_name
-        {
-            return Ok(());
-        }
+    let hint =
+        format!("(the `{expected_name}` enum is generated by the `autoroute` macro, it does not need to be imported)");
+    match &input.itemfn.sig.output {
+        // point right after the arguments, where the return type is missing
+        ReturnType::Default => syn_bail!(
+            input.itemfn.sig.paren_token.span.close(),
+            "missing return type, expecting `-> {expected_name}` {hint}"
+        ),
+        ReturnType::Type(_, box_type) => match &**box_type {
+            Type::Path(path) if path.path.get_ident().is_some_and(|ident| *ident == expected_name) => Ok(()),
+            Type::ImplTrait(_) => syn_bail!(
+                box_type.span(),
+                "`impl Trait` return types are not supported, expecting return type `{expected_name}` {hint}"
+            ),
+            _ => syn_bail!(box_type.span(), "expecting return type `{expected_name}` {hint}"),
+        },
     }
-    syn_bail!(err_span, "expecting return type `{expected_name}`")
 }
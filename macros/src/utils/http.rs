@@ -1,16 +1,19 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
 #[expect(clippy::wildcard_imports)]
 use axum::http::header::*;
 use axum::http::{HeaderName, StatusCode};
 use convert_case::{Case, Casing};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
 use strum::IntoEnumIterator;
 use syn::parse::{Parse, ParseStream};
 use syn::{Ident, LitInt};
 
 use super::spanned::SpannedValue;
 
-#[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub(crate) enum HttpMethod {
     Get,
@@ -47,10 +50,27 @@ impl Parse for SpannedValue<HttpMethod> {
     }
 }
 
-#[derive(Debug, Clone, Copy, strum::Display, strum::EnumString, strum::FromRepr, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Copy, strum::Display, strum::EnumString, strum::FromRepr, strum::EnumDiscriminants, PartialEq, Eq,
+)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[repr(u16)]
 pub(crate) enum HttpStatusCode {
+    /// Openapi catch-all response, the actual status code is provided at runtime.
+    /// Uses 0 as a sentinel value as it is not a valid http status code.
+    Default = 0,
+    /// Openapi status code ranges (e.g. `2XX`), the actual status code is provided at runtime.
+    /// Uses the first digit of the range as a sentinel value as it is not a valid http status code.
+    #[strum(serialize = "1XX")]
+    Range1xx = 1,
+    #[strum(serialize = "2XX")]
+    Range2xx = 2,
+    #[strum(serialize = "3XX")]
+    Range3xx = 3,
+    #[strum(serialize = "4XX")]
+    Range4xx = 4,
+    #[strum(serialize = "5XX")]
+    Range5xx = 5,
     Continue = 100,
     SwitchingProtocols = 101,
     Processing = 102,
@@ -112,14 +132,184 @@ pub(crate) enum HttpStatusCode {
     LoopDetected = 508,
     NotExtended = 510,
     NetworkAuthenticationRequired = 511,
+    /// A non-standard status code (e.g. `218`), only used for the codes not matching any other variant.
+    /// Uses `u16::MAX` as a sentinel value as it is not a valid http status code.
+    #[strum(to_string = "{0}")]
+    Custom(u16) = u16::MAX,
+}
+
+impl PartialOrd for HttpStatusCode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HttpStatusCode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_code().cmp(&other.as_code())
+    }
 }
 
 impl HttpStatusCode {
     pub(crate) fn as_code(self) -> u16 {
-        self as u16
+        match self {
+            Self::Custom(code) => code,
+            _ => HttpStatusCodeDiscriminants::from(self) as u16,
+        }
+    }
+
+    /// Status code from its numeric value (between 100 and 599), the non-standard ones being `Custom`
+    pub(crate) fn from_code(code: u16) -> Option<Self> {
+        if !(100..600).contains(&code) {
+            return None;
+        }
+        Some(Self::from_repr(code).unwrap_or(Self::Custom(code)))
+    }
+
+    /// Whether this is a non-standard status code
+    pub(crate) fn is_custom(self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    pub(crate) fn is_default(self) -> bool {
+        self == Self::Default
+    }
+
+    /// Whether this is an openapi status code range (e.g. `2XX`)
+    pub(crate) fn is_range(self) -> bool {
+        matches!(
+            self,
+            Self::Range1xx | Self::Range2xx | Self::Range3xx | Self::Range4xx | Self::Range5xx
+        )
+    }
+
+    /// Whether the actual status code of the response is only known at runtime (`DEFAULT` or a range)
+    pub(crate) fn is_runtime(self) -> bool {
+        self.is_default() || self.is_range()
+    }
+
+    /// Whether this is an informational status code (`1xx`, including the `1XX` range)
+    pub(crate) fn is_informational(self) -> bool {
+        self.class() == 1
+    }
+
+    /// Whether this is a successful status code (`2xx`, including the `2XX` range)
+    pub(crate) fn is_success(self) -> bool {
+        self.class() == 2
+    }
+
+    /// Whether this is a redirection status code (`3xx`, including the `3XX` range)
+    pub(crate) fn is_redirection(self) -> bool {
+        self.class() == 3
+    }
+
+    /// Whether this is a client error status code (`4xx`, including the `4XX` range)
+    pub(crate) fn is_client_error(self) -> bool {
+        self.class() == 4
+    }
+
+    /// Whether this is a server error status code (`5xx`, including the `5XX` range)
+    pub(crate) fn is_server_error(self) -> bool {
+        self.class() == 5
+    }
+
+    /// Name of the `axum::http::StatusCode` method checking that a status belongs to the same class
+    /// (e.g. `is_client_error` for `4XX`), `None` for `DEFAULT`
+    pub(crate) fn axum_class_predicate(self) -> Option<&'static str> {
+        if self.is_informational() {
+            Some("is_informational")
+        } else if self.is_success() {
+            Some("is_success")
+        } else if self.is_redirection() {
+            Some("is_redirection")
+        } else if self.is_client_error() {
+            Some("is_client_error")
+        } else if self.is_server_error() {
+            Some("is_server_error")
+        } else {
+            None
+        }
+    }
+
+    /// First digit of the status code (or of the range), 0 for `DEFAULT`
+    fn class(self) -> u16 {
+        let code = self.as_code();
+        if code < 10 { code } else { code / 100 }
+    }
+
+    /// Canonical reason phrase of the status code (e.g. `Not Found`),
+    /// or the name of the class of status codes for `DEFAULT` and the ranges (e.g. `Client Error`),
+    /// or the code itself for the non-standard status codes (e.g. `Status 420`)
+    pub(crate) fn reason_phrase(self) -> Cow<'static, str> {
+        match self {
+            Self::Default => "Default".into(),
+            Self::Range1xx => "Informational".into(),
+            Self::Range2xx => "Success".into(),
+            Self::Range3xx => "Redirection".into(),
+            Self::Range4xx => "Client Error".into(),
+            Self::Range5xx => "Server Error".into(),
+            Self::Custom(code) => format!("Status {code}").into(),
+            // every other variant is a standard status code with a canonical reason
+            _ => self
+                .as_axum()
+                .canonical_reason()
+                .expect("no canonical reason for the status code")
+                .into(),
+        }
+    }
+
+    /// Key of the response in the openapi specification (e.g. `404`, `default` or `4XX`)
+    pub(crate) fn openapi_key(self) -> String {
+        if self.is_default() {
+            "default".to_string()
+        } else if self.is_range() {
+            self.to_string()
+        } else {
+            self.as_code().to_string()
+        }
+    }
+
+    /// Name of the status in pascal case (e.g. `NotFound`, `Status4xx` or `Status218`)
+    pub(crate) fn pascal_name(self) -> String {
+        if self.is_range() || self.is_custom() {
+            format!("Status{}", self.to_string().to_lowercase())
+        } else {
+            self.to_string().to_case(Case::Pascal)
+        }
+    }
+
+    /// Name of the status in snake case (e.g. `not_found`, `status_4xx` or `status_218`)
+    pub(crate) fn snake_name(self) -> String {
+        if self.is_range() || self.is_custom() {
+            format!("status_{}", self.to_string().to_lowercase())
+        } else {
+            self.to_string().to_case(Case::Snake)
+        }
+    }
+
+    /// Expression of the `axum::http::StatusCode`, for the status codes known at compile time
+    pub(crate) fn axum_status(self, span: Span) -> TokenStream {
+        if let Self::Custom(code) = self {
+            quote_spanned! {span=> axum::http::status::StatusCode::from_u16(#code).expect("invalid status code")}
+        } else {
+            let status_code_ident = Ident::new(&self.to_string(), span);
+            quote_spanned! {span=> axum::http::status::StatusCode::#status_code_ident}
+        }
+    }
+
+    /// Value of the `status` field of a response in the `utoipa::path` macro
+    pub(crate) fn utoipa_status(self, span: Span) -> TokenStream {
+        if self.is_runtime() {
+            let status_key = self.openapi_key();
+            quote_spanned! {span=> #status_key}
+        } else if let Self::Custom(code) = self {
+            quote_spanned! {span=> #code}
+        } else {
+            let status_code_ident = Ident::new(&self.to_string(), span);
+            quote! {#status_code_ident}
+        }
     }
 
-    #[expect(unused)]
     pub(crate) fn as_axum(self) -> StatusCode {
         StatusCode::from_u16(self.as_code()).expect("unable to convert status to an axum code")
     }
@@ -130,7 +320,7 @@ impl Parse for SpannedValue<HttpStatusCode> {
         let status_error = |span| {
             syn::Error::new(
                 span,
-                "unexpected status code, should be a numeric status code or a constant as described in https://docs.rs/http/latest/http/status/struct.StatusCode.html",
+                "unexpected status code, should be a numeric status code (between 100 and 599), a constant as described in https://docs.rs/http/latest/http/status/struct.StatusCode.html, a range (1XX, 2XX, 3XX, 4XX or 5XX) or DEFAULT",
             )
         };
 
@@ -139,8 +329,16 @@ impl Parse for SpannedValue<HttpStatusCode> {
                 .map(|sc| SpannedValue::new(sc, ident.span()))
                 .map_err(|_| status_error(ident.span()))
         } else if let Ok(code_lit) = input.parse::<LitInt>() {
+            // ranges are parsed as integer literals with a `XX` suffix (e.g. `4XX`)
+            if code_lit.suffix().eq_ignore_ascii_case("xx") {
+                return HttpStatusCode::from_str(&format!("{}XX", code_lit.base10_digits()))
+                    .ok()
+                    .filter(|sc| sc.is_range())
+                    .map(|sc| SpannedValue::new(sc, code_lit.span()))
+                    .ok_or(status_error(code_lit.span()));
+            }
             let code_num: u16 = code_lit.base10_parse()?;
-            HttpStatusCode::from_repr(code_num)
+            HttpStatusCode::from_code(code_num)
                 .map(|sc| SpannedValue::new(sc, code_lit.span()))
                 .ok_or(status_error(code_lit.span()))
         } else {
@@ -251,3 +449,84 @@ pub(crate) fn parse_header_name(input: ParseStream) -> syn::Result<SpannedValue<
 
     Err(header_error(header_ident.span()))
 }
+
+#[cfg(test)]
+mod test {
+    use super::HttpStatusCode;
+
+    #[test]
+    fn status_code_reason_phrase() {
+        assert_eq!(HttpStatusCode::Ok.reason_phrase(), "OK");
+        assert_eq!(HttpStatusCode::NotFound.reason_phrase(), "Not Found");
+        assert_eq!(HttpStatusCode::ImATeapot.reason_phrase(), "I'm a teapot");
+        assert_eq!(
+            HttpStatusCode::InternalServerError.reason_phrase(),
+            "Internal Server Error"
+        );
+        assert_eq!(HttpStatusCode::Range4xx.reason_phrase(), "Client Error");
+        assert_eq!(HttpStatusCode::Default.reason_phrase(), "Default");
+        assert_eq!(HttpStatusCode::Custom(420).reason_phrase(), "Status 420");
+
+        // every known status code has a reason phrase
+        for code in 100..600 {
+            if let Some(status) = HttpStatusCode::from_repr(code) {
+                assert!(!status.reason_phrase().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn status_code_class() {
+        let classes = |code: HttpStatusCode| {
+            (
+                code.is_informational(),
+                code.is_success(),
+                code.is_redirection(),
+                code.is_client_error(),
+                code.is_server_error(),
+            )
+        };
+
+        assert_eq!(classes(HttpStatusCode::Continue), (true, false, false, false, false));
+        assert_eq!(classes(HttpStatusCode::Range1xx), (true, false, false, false, false));
+        assert_eq!(classes(HttpStatusCode::Ok), (false, true, false, false, false));
+        assert_eq!(classes(HttpStatusCode::ImUsed), (false, true, false, false, false));
+        assert_eq!(classes(HttpStatusCode::Custom(299)), (false, true, false, false, false));
+        assert_eq!(classes(HttpStatusCode::Range2xx), (false, true, false, false, false));
+        assert_eq!(
+            classes(HttpStatusCode::MultipleChoices),
+            (false, false, true, false, false)
+        );
+        assert_eq!(classes(HttpStatusCode::Range3xx), (false, false, true, false, false));
+        assert_eq!(classes(HttpStatusCode::BadRequest), (false, false, false, true, false));
+        assert_eq!(classes(HttpStatusCode::Custom(499)), (false, false, false, true, false));
+        assert_eq!(classes(HttpStatusCode::Range4xx), (false, false, false, true, false));
+        assert_eq!(
+            classes(HttpStatusCode::InternalServerError),
+            (false, false, false, false, true)
+        );
+        assert_eq!(classes(HttpStatusCode::Custom(599)), (false, false, false, false, true));
+        assert_eq!(classes(HttpStatusCode::Range5xx), (false, false, false, false, true));
+        assert_eq!(classes(HttpStatusCode::Default), (false, false, false, false, false));
+    }
+
+    #[test]
+    fn status_code_from_code() {
+        assert_eq!(HttpStatusCode::from_code(200), Some(HttpStatusCode::Ok));
+        assert_eq!(HttpStatusCode::from_code(218), Some(HttpStatusCode::Custom(218)));
+        assert_eq!(HttpStatusCode::from_code(100), Some(HttpStatusCode::Continue));
+        assert_eq!(HttpStatusCode::from_code(599), Some(HttpStatusCode::Custom(599)));
+        assert_eq!(HttpStatusCode::from_code(99), None);
+        assert_eq!(HttpStatusCode::from_code(600), None);
+        // the sentinel values are not valid status codes
+        assert_eq!(HttpStatusCode::from_code(0), None);
+        assert_eq!(HttpStatusCode::from_code(4), None);
+
+        let custom = HttpStatusCode::Custom(420);
+        assert_eq!(custom.as_code(), 420);
+        assert_eq!(custom.openapi_key(), "420");
+        assert_eq!(custom.pascal_name(), "Status420");
+        assert_eq!(custom.snake_name(), "status_420");
+        assert!(HttpStatusCode::NotFound < custom);
+    }
+}
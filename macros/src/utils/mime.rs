@@ -48,6 +48,8 @@ pub(crate) enum KnownMimes {
     ApplicationOctetStream,
     ApplicationMsgpack,
     ApplicationPdf,
+    ApplicationYaml,
+    ApplicationCbor,
     MultipartFormData,
 }
 
@@ -85,6 +87,9 @@ impl From<KnownMimes> for Mime {
             KnownMimes::ApplicationOctetStream => APPLICATION_OCTET_STREAM,
             KnownMimes::ApplicationMsgpack => APPLICATION_MSGPACK,
             KnownMimes::ApplicationPdf => APPLICATION_PDF,
+            // not defined as constants by the mime crate
+            KnownMimes::ApplicationYaml => Mime::from_str("application/yaml").expect("valid mime string"),
+            KnownMimes::ApplicationCbor => Mime::from_str("application/cbor").expect("valid mime string"),
             KnownMimes::MultipartFormData => MULTIPART_FORM_DATA,
         }
     }
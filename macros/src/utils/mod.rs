@@ -12,6 +12,7 @@ pub(crate) mod error;
 pub(crate) mod http;
 pub(crate) mod mime;
 pub(crate) mod spanned;
+pub(crate) mod trace_level;
 
 pub(crate) fn parse_named_ident(input: ParseStream, name: &str) -> syn::Result<Ident> {
     // fork to avoid advancing the input if not found
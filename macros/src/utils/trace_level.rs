@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+/// Level of the traces generated when the `tracing` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum TraceLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Default for TraceLevel {
+    /// The default level is selected by the `tracing_level_xxx` features (`debug` if none of them is enabled).
+    /// The features are additive: if several of them are enabled, the most verbose level is used.
+    fn default() -> Self {
+        if cfg!(feature = "tracing_level_trace") {
+            Self::Trace
+        } else if cfg!(feature = "tracing_level_info") && !cfg!(feature = "tracing_level_debug") {
+            Self::Info
+        } else {
+            Self::Debug
+        }
+    }
+}
+
+impl TraceLevel {
+    /// Path of the `tracing` macro emitting an event at this level (e.g. `tracing::debug`).
+    #[cfg(feature = "tracing")]
+    pub(crate) fn tracing_macro(self, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+        let ident = Ident::new(&self.to_string(), span);
+        quote::quote_spanned! {span=> tracing::#ident}
+    }
+}
+
+impl Parse for TraceLevel {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let level_error = |span| {
+            syn::Error::new(
+                span,
+                format!(
+                    "unexpected trace level, should be one of: {}",
+                    Self::iter()
+                        .map(|level| level.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        };
+
+        let ident: Ident = input.parse().map_err(|e| level_error(e.span()))?;
+        Self::from_str(&ident.to_string()).map_err(|_| level_error(ident.span()))
+    }
+}
@@ -4,7 +4,8 @@ set -e
 
 ROOTDIR=`cd $(dirname $0)/..; pwd`
 
-opt_features=("--no-default-features" "--all-features")
+# the human-readable traces are checked with `tracing`, the structured ones with `--all-features` (enabling `tracing_json`)
+opt_features=("--no-default-features" "--features=tracing" "--all-features")
 
 for opt_feature in ${opt_features[@]}; do
     echo "################################################################################"